@@ -0,0 +1,306 @@
+use crate::functions::Point;
+use crate::method::Optimizer;
+use crate::utils::{cauchy, normal};
+use nalgebra::SVector;
+use rand::{RngExt, rng};
+use std::ops::RangeInclusive;
+
+/// Temperature after `k` epochs, starting from `T_0`.
+#[derive(Debug, Clone, Copy)]
+pub enum Cooling {
+    /// `T_k = T_0 alpha^k`.
+    Exponential { alpha: f64 },
+    /// `T_k = T_0 ln 2 / ln(k + 2)`, slow enough for convergence in probability.
+    Logarithmic,
+    /// Fast annealing `T_k = T_0 / (k + 1)`.
+    Cauchy,
+    /// Exponential cooling sped up while more than `target` of the moves are accepted and slowed
+    /// down below it.
+    Adaptive { alpha: f64, target: f64 },
+}
+
+impl Cooling {
+    fn temperature(&self, initial: f64, current: f64, k: usize, acceptance: f64) -> f64 {
+        let k = k as f64;
+        match *self {
+            Cooling::Exponential { alpha } => initial * alpha.powf(k),
+            Cooling::Logarithmic => initial * 2f64.ln() / (k + 2.0).ln(),
+            Cooling::Cauchy => initial / (k + 1.0),
+            Cooling::Adaptive { alpha, target } => {
+                current * alpha.powf((acceptance / target).clamp(0.5, 2.0))
+            }
+        }
+    }
+}
+
+/// Candidate generator around the current point. Steps are scaled by `sqrt(T / T_0)`, so they shrink
+/// as the system cools.
+#[derive(Debug, Clone, Copy)]
+pub enum Neighbourhood {
+    Gaussian {
+        sigma: f64,
+    },
+    /// Heavy tailed steps that keep occasional long jumps at low temperatures.
+    Cauchy {
+        gamma: f64,
+    },
+    /// Uniform in the cube of half-width `radius`.
+    Uniform {
+        radius: f64,
+    },
+}
+
+impl Neighbourhood {
+    fn candidate<const N: usize>(&self, x: &Point<N>, scale: f64) -> Point<N> {
+        let mut random = rng();
+        let step = Point::<N>::from_fn(|_, _| match *self {
+            Neighbourhood::Gaussian { sigma } => sigma * normal(&mut random),
+            Neighbourhood::Cauchy { gamma } => gamma * cauchy(&mut random),
+            Neighbourhood::Uniform { radius } => random.random_range(-radius..=radius),
+        });
+        x + scale * step
+    }
+}
+
+/// Returns to the best point and the temperature it was found at after `patience` epochs without
+/// improving it, at most `limit` times.
+#[derive(Debug, Clone, Copy)]
+pub struct Reheating {
+    pub patience: usize,
+    pub limit: usize,
+}
+
+#[derive(Debug)]
+pub struct AnnealingInfo {
+    pub epochs: usize,
+    pub evaluations: usize,
+    pub reheats: usize,
+    pub temperature: f64,
+    /// Fraction of accepted moves in each epoch.
+    pub acceptance: Vec<f64>,
+}
+
+/// Simulated annealing with Metropolis acceptance, returns the best point visited.
+#[derive(Clone)]
+pub struct SimulatedAnnealing<const N: usize> {
+    start: Point<N>,
+    temperature: f64,
+    cooling: Cooling,
+    neighbourhood: Neighbourhood,
+    bounds: Option<SVector<RangeInclusive<f64>, N>>,
+    reheating: Option<Reheating>,
+    epoch: usize,
+    min_temperature: f64,
+    max_evaluations: usize,
+}
+
+impl<const N: usize> SimulatedAnnealing<N> {
+    pub fn new(
+        start: Point<N>,
+        temperature: f64,
+        cooling: Cooling,
+        neighbourhood: Neighbourhood,
+    ) -> Self {
+        Self {
+            start,
+            temperature,
+            cooling,
+            neighbourhood,
+            bounds: None,
+            reheating: None,
+            epoch: 20 * N,
+            min_temperature: 1e-8 * temperature,
+            max_evaluations: 100_000 * N,
+        }
+    }
+
+    /// Candidates are clamped into the box.
+    pub fn with_bounds(self, bounds: [RangeInclusive<f64>; N]) -> Self {
+        Self {
+            bounds: Some(bounds.into()),
+            ..self
+        }
+    }
+
+    pub fn with_reheating(self, reheating: Reheating) -> Self {
+        Self {
+            reheating: Some(reheating),
+            ..self
+        }
+    }
+
+    /// Number of moves made at each temperature.
+    pub fn with_epoch(self, epoch: usize) -> Self {
+        Self { epoch, ..self }
+    }
+
+    pub fn with_min_temperature(self, min_temperature: f64) -> Self {
+        Self {
+            min_temperature,
+            ..self
+        }
+    }
+
+    pub fn with_max_evaluations(self, max_evaluations: usize) -> Self {
+        Self {
+            max_evaluations,
+            ..self
+        }
+    }
+
+    fn clamp(&self, x: Point<N>) -> Point<N> {
+        match &self.bounds {
+            Some(bounds) => x.zip_map(bounds, |x, range| x.clamp(*range.start(), *range.end())),
+            None => x,
+        }
+    }
+}
+
+impl<const N: usize> Optimizer for SimulatedAnnealing<N> {
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = AnnealingInfo;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let mut random = rng();
+        let mut x = self.clamp(self.start);
+        let mut fx = f(x);
+        let (mut best, mut f_best) = (x, fx);
+
+        let mut temperature = self.temperature;
+        let mut acceptance = vec![];
+        let mut evaluations = 1;
+        let (mut k, mut stagnation, mut reheats) = (0, 0, 0);
+        let mut found_at = (k, temperature);
+
+        while temperature > self.min_temperature && evaluations < self.max_evaluations {
+            let scale = (temperature / self.temperature).sqrt();
+            let (mut accepted, mut moves) = (0, 0);
+            let mut improved = false;
+
+            while moves < self.epoch && evaluations < self.max_evaluations {
+                moves += 1;
+                let candidate = self.clamp(self.neighbourhood.candidate(&x, scale));
+                let f_candidate = f(candidate);
+                evaluations += 1;
+
+                let delta = f_candidate - fx;
+                if delta <= 0.0 || random.random::<f64>() < (-delta / temperature).exp() {
+                    (x, fx) = (candidate, f_candidate);
+                    accepted += 1;
+                }
+                if fx < f_best {
+                    (best, f_best) = (x, fx);
+                    improved = true;
+                }
+            }
+
+            let rate = accepted as f64 / moves as f64;
+            acceptance.push(rate);
+            if improved {
+                found_at = (k, temperature);
+                stagnation = 0;
+            } else {
+                stagnation += 1;
+            }
+            k += 1;
+
+            match self.reheating {
+                Some(Reheating { patience, limit })
+                    if stagnation >= patience && reheats < limit =>
+                {
+                    (x, fx) = (best, f_best);
+                    (k, temperature) = found_at;
+                    stagnation = 0;
+                    reheats += 1;
+                }
+                _ => {
+                    temperature = self
+                        .cooling
+                        .temperature(self.temperature, temperature, k, rate)
+                }
+            }
+        }
+
+        let info = AnnealingInfo {
+            epochs: acceptance.len(),
+            evaluations,
+            reheats,
+            temperature,
+            acceptance,
+        };
+        (best, f_best, info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::annealing::{Cooling, Neighbourhood, Reheating, SimulatedAnnealing};
+    use crate::functions::{Booth, Function, Himmelblau, Rastrigin, Sphere};
+    use crate::task::Task;
+    use test_case::test_case;
+
+    #[test_case(Cooling::Exponential { alpha: 0.97 }; "exponential")]
+    #[test_case(Cooling::Cauchy; "cauchy")]
+    #[test_case(Cooling::Adaptive { alpha: 0.97, target: 0.3 }; "adaptive")]
+    fn test_rastrigin(cooling: Cooling) {
+        let neighbourhood = Neighbourhood::Cauchy { gamma: 1.0 };
+        let optimizer = SimulatedAnnealing::new([3.3, -4.1].into(), 10.0, cooling, neighbourhood)
+            .with_bounds([-5.12..=5.12, -5.12..=5.12])
+            .with_reheating(Reheating {
+                patience: 100,
+                limit: 3,
+            });
+
+        Task::new(optimizer, Rastrigin)
+            .solve_space_check()
+            .with_eps_x(1e-2)
+            .with_eps_y(1e-2)
+            .check();
+    }
+
+    #[test_case(Booth, Neighbourhood::Gaussian { sigma: 1.0 }; "booth_gaussian")]
+    #[test_case(Himmelblau, Neighbourhood::Uniform { radius: 2.0 }; "himmelblau_uniform")]
+    #[test_case(Sphere, Neighbourhood::Cauchy { gamma: 1.0 }; "sphere_cauchy")]
+    fn test_exponential<F: Function<2>>(f: F, neighbourhood: Neighbourhood) {
+        Task::new(
+            SimulatedAnnealing::new(
+                [0.0, 0.0].into(),
+                10.0,
+                Cooling::Exponential { alpha: 0.95 },
+                neighbourhood,
+            ),
+            f,
+        )
+        .solve_space_check()
+        .with_eps_x(1e-3)
+        .with_eps_y(1e-4)
+        .check();
+    }
+
+    #[test]
+    fn test_logarithmic() {
+        let (_, f, info) = Task::new(
+            SimulatedAnnealing::new(
+                [3.0, 3.0].into(),
+                1.0,
+                Cooling::Logarithmic,
+                Neighbourhood::Gaussian { sigma: 0.1 },
+            )
+            .with_max_evaluations(20_000),
+            Sphere,
+        )
+        .solve_space();
+
+        assert!(f < 1e-2);
+        assert!(info.evaluations <= 20_000);
+        assert!(
+            info.acceptance
+                .iter()
+                .all(|rate| (0.0..=1.0).contains(rate))
+        );
+    }
+}
@@ -21,6 +21,10 @@ impl ApproxModel {
         Self { range, n, m, eps }
     }
 
+    pub fn with_range(self, range: RangeInclusive<f64>) -> Self {
+        Self { range, ..self }
+    }
+
     fn build_polynomial(&self, f: &impl Fn(f64) -> f64) -> (Polynomial<f64>, Steps) {
         let a = *self.range.start();
         let b = *self.range.end();
@@ -1,6 +1,6 @@
+use crate::evolution::{StepControl, one_fifth};
 use crate::functions::Point;
 use crate::method::{Optimizer, Steps};
-use derive_more::Constructor;
 use ordered_float::OrderedFloat;
 use std::mem::swap;
 
@@ -11,6 +11,7 @@ pub struct Backward<const N: usize> {
     k: usize,
     alpha: f64,
     eps: f64,
+    control: StepControl,
 }
 
 impl<const N: usize> Backward<N> {
@@ -21,8 +22,14 @@ impl<const N: usize> Backward<N> {
             k: 3 * N,
             alpha,
             eps,
+            control: StepControl::Classic,
         }
     }
+
+    /// With [`StepControl::OneFifth`] the step is adapted after every try and `alpha` is unused.
+    pub fn with_step_control(self, control: StepControl) -> Self {
+        Self { control, ..self }
+    }
 }
 
 impl<const N: usize> Optimizer for Backward<N> {
@@ -41,14 +48,21 @@ impl<const N: usize> Optimizer for Backward<N> {
 
         while (f(x) - f(x_)).abs() > self.eps {
             let mut k = 1;
+            let fx = f(x);
+            let mut fx_ = f(x_);
 
-            while f(x) < f(x_) && k < self.k {
+            while fx < fx_ && k < self.k {
                 let phi: Point<N> = Point::new_random() * 2.0 - Point::from_element(1.0);
                 x_ = x + step * phi.normalize();
+                fx_ = f(x_);
                 k += 1;
+
+                if let StepControl::OneFifth = self.control {
+                    step = one_fifth(step, fx_ < fx);
+                }
             }
 
-            if k == self.k {
+            if k == self.k && matches!(self.control, StepControl::Classic) {
                 step *= self.alpha;
             }
 
@@ -60,13 +74,32 @@ impl<const N: usize> Optimizer for Backward<N> {
     }
 }
 
-#[derive(Constructor)]
 pub struct BestChoice<const N: usize> {
     start: Point<N>,
     step: f64,
     k: usize,
     alpha: f64,
     eps: f64,
+    control: StepControl,
+}
+
+impl<const N: usize> BestChoice<N> {
+    pub fn new(start: Point<N>, step: f64, k: usize, alpha: f64, eps: f64) -> Self {
+        Self {
+            start,
+            step,
+            k,
+            alpha,
+            eps,
+            control: StepControl::Classic,
+        }
+    }
+
+    /// With [`StepControl::OneFifth`] the step is adapted after every draw of `k` directions and
+    /// `alpha` is unused.
+    pub fn with_step_control(self, control: StepControl) -> Self {
+        Self { control, ..self }
+    }
 }
 
 impl<const N: usize> Optimizer for BestChoice<N> {
@@ -85,13 +118,26 @@ impl<const N: usize> Optimizer for BestChoice<N> {
 
         while (f(x) - f(x_)).abs() > self.eps {
             while f(x_) > f(x) {
-                let ps = (0..self.k)
-                    .map(|_| Point::new_random() * 2.0 - Point::from_element(1.0))
-                    .min_by_key(|p| OrderedFloat(f(*p)));
-                x_ = x + step * ps.unwrap();
+                let ps = (0..self.k).map(|_| Point::new_random() * 2.0 - Point::from_element(1.0));
+
+                match self.control {
+                    StepControl::Classic => {
+                        x_ = x + step * ps.min_by_key(|p| OrderedFloat(f(*p))).unwrap();
+                    }
+                    // The success rate only makes sense for the candidate points themselves
+                    StepControl::OneFifth => {
+                        x_ = ps
+                            .map(|p| x + step * p)
+                            .min_by_key(|x_| OrderedFloat(f(*x_)))
+                            .unwrap();
+                        step = one_fifth(step, f(x_) <= f(x));
+                    }
+                }
             }
 
-            step *= self.alpha;
+            if let StepControl::Classic = self.control {
+                step *= self.alpha;
+            }
             swap(&mut x, &mut x_);
             r += 1;
         }
@@ -103,8 +149,10 @@ impl<const N: usize> Optimizer for BestChoice<N> {
 #[cfg(test)]
 mod tests {
     use crate::backward::{Backward, BestChoice};
-    use crate::functions::{Booth, Sphere};
+    use crate::evolution::StepControl;
+    use crate::functions::{Booth, Function, Himmelblau, Sphere};
     use crate::task::Task;
+    use test_case::test_case;
 
     #[test]
     fn test_backward_sphere() {
@@ -120,6 +168,19 @@ mod tests {
             .check();
     }
 
+    #[test_case(Sphere)]
+    #[test_case(Booth)]
+    #[test_case(Himmelblau)]
+    fn test_backward_one_fifth<F: Function<2>>(f: F) {
+        Task::new(
+            Backward::new([-10.0, -5.0].into(), 10.0, 0.5, 1e-15)
+                .with_step_control(StepControl::OneFifth),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
     #[test]
     fn test_best_choice_sphere() {
         Task::new(
@@ -138,4 +199,16 @@ mod tests {
             .with_eps_x(1e-3)
             .check();
     }
+
+    #[test]
+    fn test_best_choice_one_fifth() {
+        Task::new(
+            BestChoice::new([1.5, 3.2].into(), 8., 6, 0.7, 1e-9)
+                .with_step_control(StepControl::OneFifth),
+            Booth,
+        )
+        .solve_space_check()
+        .with_eps_x(1e-3)
+        .check();
+    }
 }
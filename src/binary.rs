@@ -53,6 +53,10 @@ impl Binary {
             delta: delta.into(),
         }
     }
+
+    pub fn with_range(self, range: RangeInclusive<<Self as Optimizer>::X>) -> Self {
+        Self { range, ..self }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,159 @@
+use crate::method::{OneDimensionalMethod, Optimizer, Steps};
+use derive_more::Constructor;
+use std::ops::RangeInclusive;
+
+/// Triple `a < b < c` with `f(b)` not greater than `f(a)` and `f(c)`.
+#[derive(Debug, Clone, Copy)]
+pub struct Bracket {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+impl Bracket {
+    fn ordered(a: f64, b: f64, c: f64) -> Self {
+        if a < c {
+            Self { a, b, c }
+        } else {
+            Self { a: c, b, c: a }
+        }
+    }
+
+    pub fn range(&self) -> RangeInclusive<f64> {
+        self.a..=self.c
+    }
+}
+
+/// Swann's expanding search: walks downhill from `start` doubling the step until the function
+/// starts to grow.
+#[derive(Constructor, Clone)]
+pub struct Swann {
+    start: f64,
+    step: f64,
+    max_steps: usize,
+}
+
+impl Swann {
+    pub fn find(&self, mut f: impl FnMut(f64) -> f64) -> Option<(Bracket, Steps)> {
+        let mut h = self.step;
+        let (mut a, fa) = (self.start, f(self.start));
+        let (mut b, mut fb) = (a + h, f(a + h));
+
+        if fb > fa {
+            let (c, fc) = (a - h, f(a - h));
+            if fc >= fa {
+                return Some((Bracket::ordered(c, a, b), Steps(1)));
+            }
+            (b, fb) = (c, fc);
+            h = -h;
+        }
+
+        for r in 1..=self.max_steps {
+            h *= 2.0;
+            let (c, fc) = (b + h, f(b + h));
+            if fc >= fb {
+                return Some((Bracket::ordered(a, b, c), Steps(r)));
+            }
+            a = b;
+            (b, fb) = (c, fc);
+        }
+
+        None
+    }
+}
+
+/// Runs `inner` on the interval found by [`Swann`], falling back to its own range when the
+/// search does not find one.
+#[derive(Constructor, Clone)]
+pub struct Bracketed {
+    swann: Swann,
+    inner: OneDimensionalMethod,
+}
+
+impl Bracketed {
+    pub fn with_range(self, range: RangeInclusive<f64>) -> Self {
+        Self {
+            inner: self.inner.with_range(range),
+            ..self
+        }
+    }
+}
+
+impl Optimizer for Bracketed {
+    type X = f64;
+    type F = f64;
+    type Metadata = Steps;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        match self.swann.find(&mut f) {
+            Some((bracket, Steps(r))) => {
+                let (x, y, Steps(k)) = self.inner.clone().with_range(bracket.range()).optimize(f);
+                (x, y, Steps(r + k))
+            }
+            None => self.inner.optimize(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bracket::Swann;
+    use crate::fibonacci::GoldenRatio;
+    use crate::functions::{Booth, Sphere};
+    use crate::method::OneDimensionalMethod;
+    use crate::task::Task;
+    use crate::zeidel::GaussZeidel;
+
+    #[test]
+    fn test_swann_far_minimum() {
+        let (bracket, _) = Swann::new(0.0, 0.1, 50)
+            .find(|x| (x - 42.0).powi(2))
+            .unwrap();
+
+        assert!(bracket.a < 42.0 && 42.0 < bracket.c);
+        assert!(bracket.a < bracket.b && bracket.b < bracket.c);
+    }
+
+    #[test]
+    fn test_swann_backwards() {
+        let (bracket, _) = Swann::new(0.0, 0.1, 50)
+            .find(|x| (x + 7.0).powi(2))
+            .unwrap();
+
+        assert!(bracket.a < -7.0 && -7.0 < bracket.c);
+    }
+
+    #[test]
+    fn test_swann_unbounded() {
+        assert!(Swann::new(0.0, 0.1, 20).find(|x| -x).is_none());
+    }
+
+    #[test]
+    fn test_auto_bracket_sphere() {
+        let method = OneDimensionalMethod::from(GoldenRatio::new(20.0..=30.0, 1e-6));
+
+        Task::new(method.auto_bracket(25.0, 0.5, 50), Sphere)
+            .solve_check()
+            .check();
+    }
+
+    #[test]
+    fn test_gauss_zeidel_booth_auto_bracket() {
+        let method = OneDimensionalMethod::from(GoldenRatio::new(-1.0..=1.0, 1e-9));
+
+        Task::new(
+            GaussZeidel::new(
+                [-20.0, -20.0].into(),
+                method.auto_bracket(0.0, 0.1, 50),
+                1e-12,
+                1e-12,
+            ),
+            Booth,
+        )
+        .solve_space_check()
+        .check();
+    }
+}
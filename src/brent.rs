@@ -0,0 +1,156 @@
+use crate::method::{Evaluations, Optimizer, Steps};
+use derive_more::Constructor;
+use std::ops::RangeInclusive;
+
+/// Brent's minimizer: parabolic interpolation safeguarded by golden section steps.
+#[derive(Constructor, Clone)]
+pub struct Brent {
+    range: RangeInclusive<f64>,
+    eps: f64,
+}
+
+impl Brent {
+    const CGOLD: f64 = 0.381966011250105;
+
+    pub fn with_range(self, range: RangeInclusive<f64>) -> Self {
+        Self { range, ..self }
+    }
+}
+
+impl Optimizer for Brent {
+    type X = f64;
+    type F = f64;
+    type Metadata = (Steps, Evaluations);
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let mut a = *self.range.start();
+        let mut b = *self.range.end();
+        let mut x = a + Self::CGOLD * (b - a);
+        let (mut w, mut v) = (x, x);
+        let mut fx = f(x);
+        let (mut fw, mut fv) = (fx, fx);
+        let mut d: f64 = 0.0;
+        let mut e: f64 = 0.0;
+        let mut r = 0;
+        let mut evaluations = 1;
+
+        loop {
+            let m = (a + b) / 2.0;
+            let tol = f64::EPSILON.sqrt() * x.abs() + self.eps / 4.0;
+
+            if (x - m).abs() <= 2.0 * tol - (b - a) / 2.0 {
+                break;
+            }
+
+            let mut golden = true;
+            if e.abs() > tol {
+                let t = (x - w) * (fx - fv);
+                let mut q = (x - v) * (fx - fw);
+                let mut p = (x - v) * q - (x - w) * t;
+                q = 2.0 * (q - t);
+                if q > 0.0 {
+                    p = -p;
+                } else {
+                    q = -q;
+                }
+
+                if p.abs() < (0.5 * q * e).abs() && p > q * (a - x) && p < q * (b - x) {
+                    e = d;
+                    d = p / q;
+                    let u = x + d;
+                    if u - a < 2.0 * tol || b - u < 2.0 * tol {
+                        d = if x < m { tol } else { -tol };
+                    }
+                    golden = false;
+                }
+            }
+
+            if golden {
+                e = if x < m { b - x } else { a - x };
+                d = Self::CGOLD * e;
+            }
+
+            let u = if d.abs() >= tol {
+                x + d
+            } else if d > 0.0 {
+                x + tol
+            } else {
+                x - tol
+            };
+            let fu = f(u);
+            evaluations += 1;
+
+            if fu <= fx {
+                if u < x {
+                    b = x;
+                } else {
+                    a = x;
+                }
+                (v, fv) = (w, fw);
+                (w, fw) = (x, fx);
+                (x, fx) = (u, fu);
+            } else {
+                if u < x {
+                    a = u;
+                } else {
+                    b = u;
+                }
+                if fu <= fw || w == x {
+                    (v, fv) = (w, fw);
+                    (w, fw) = (u, fu);
+                } else if fu <= fv || v == x || v == w {
+                    (v, fv) = (u, fu);
+                }
+            }
+            r += 1;
+        }
+
+        (x, fx, (Steps(r), Evaluations(evaluations)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::brent::Brent;
+    use crate::fibonacci::GoldenRatio;
+    use crate::functions::{Sphere, Tang};
+    use crate::method::Optimizer;
+    use crate::task::Task;
+
+    #[test]
+    fn test_brent_tang() {
+        Task::new(Brent::new(-5.0..=0.0, 1e-8), Tang)
+            .solve_check()
+            .check();
+    }
+
+    #[test]
+    fn test_brent_sphere() {
+        Task::new(Brent::new(-5.0..=0.0, 1e-8), Sphere)
+            .solve_check()
+            .check();
+    }
+
+    #[test]
+    fn test_brent_faster_than_golden_ratio() {
+        let f = |x: f64| (x - 1.0).powi(2) + x.exp();
+
+        let mut brent_calls = 0;
+        let (_, _, (_, evaluations)) = Brent::new(-5.0..=5.0, 1e-8).optimize(|x| {
+            brent_calls += 1;
+            f(x)
+        });
+
+        let mut golden_calls = 0;
+        GoldenRatio::new(-5.0..=5.0, 1e-8).optimize(|x| {
+            golden_calls += 1;
+            f(x)
+        });
+
+        assert_eq!(evaluations.0, brent_calls);
+        assert!(brent_calls < golden_calls);
+    }
+}
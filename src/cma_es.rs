@@ -0,0 +1,397 @@
+use crate::functions::Point;
+use crate::method::Optimizer;
+use crate::utils::normal;
+use nalgebra::{DMatrix, DVector, SymmetricEigen};
+use ordered_float::OrderedFloat;
+use rand::{RngExt, rng};
+
+/// Restart strategy applied after a run converges.
+#[derive(Debug, Clone, Copy)]
+pub enum Restarts {
+    None,
+    /// Doubles the population at each of the `restarts` restarts.
+    Ipop {
+        restarts: usize,
+    },
+    /// Interleaves the `restarts` doubling runs with runs of small populations and step sizes,
+    /// choosing the regime that has used fewer evaluations so far.
+    Bipop {
+        restarts: usize,
+    },
+}
+
+#[derive(Debug)]
+pub struct CmaInfo {
+    pub generations: usize,
+    pub evaluations: usize,
+    pub restarts: usize,
+    /// Step size and covariance of the run that found the returned point.
+    pub sigma: f64,
+    pub covariance: DMatrix<f64>,
+}
+
+/// Covariance matrix adaptation evolution strategy with cumulative step-size adaptation.
+///
+/// A run stops when the standard deviation along every axis falls below `eps`. Restarted runs
+/// begin at a point drawn uniformly within `sigma` of the start.
+#[derive(Clone)]
+pub struct CmaEs<X> {
+    start: X,
+    sigma: f64,
+    lambda: Option<usize>,
+    restarts: Restarts,
+    eps: f64,
+    max_evaluations: usize,
+}
+
+impl<X> CmaEs<X> {
+    pub fn new(start: X, sigma: f64, eps: f64) -> Self {
+        Self {
+            start,
+            sigma,
+            lambda: None,
+            restarts: Restarts::None,
+            eps,
+            max_evaluations: 1_000_000,
+        }
+    }
+
+    /// Population size, `4 + 3 ln N` by default.
+    pub fn with_lambda(self, lambda: usize) -> Self {
+        assert!(
+            lambda >= 2,
+            "CMA-ES needs at least two offspring to recombine"
+        );
+        Self {
+            lambda: Some(lambda),
+            ..self
+        }
+    }
+
+    pub fn with_restarts(self, restarts: Restarts) -> Self {
+        Self { restarts, ..self }
+    }
+
+    pub fn with_max_evaluations(self, max_evaluations: usize) -> Self {
+        Self {
+            max_evaluations,
+            ..self
+        }
+    }
+}
+
+/// Outcome of a single run.
+struct Run {
+    x: DVector<f64>,
+    f: f64,
+    generations: usize,
+    evaluations: usize,
+    sigma: f64,
+    covariance: DMatrix<f64>,
+}
+
+fn run(
+    mean: DVector<f64>,
+    mut sigma: f64,
+    lambda: usize,
+    eps: f64,
+    budget: usize,
+    f: &mut impl FnMut(&DVector<f64>) -> f64,
+) -> Run {
+    let n = mean.len();
+    let nf = n as f64;
+    let mu = lambda / 2;
+
+    let weights = DVector::from_fn(mu, |i, _| {
+        ((lambda as f64 + 1.0) / 2.0).ln() - ((i + 1) as f64).ln()
+    });
+    let weights = &weights / weights.sum();
+    let mu_eff = 1.0 / weights.norm_squared();
+
+    let cc = (4.0 + mu_eff / nf) / (nf + 4.0 + 2.0 * mu_eff / nf);
+    let cs = (mu_eff + 2.0) / (nf + mu_eff + 5.0);
+    let c1 = 2.0 / ((nf + 1.3).powi(2) + mu_eff);
+    let c_mu = (1.0 - c1).min(2.0 * (mu_eff - 2.0 + 1.0 / mu_eff) / ((nf + 2.0).powi(2) + mu_eff));
+    let damps = 1.0 + 2.0 * (((mu_eff - 1.0) / (nf + 1.0)).sqrt() - 1.0).max(0.0) + cs;
+    let chi_n = nf.sqrt() * (1.0 - 1.0 / (4.0 * nf) + 1.0 / (21.0 * nf * nf));
+
+    let mut mean = mean;
+    let mut covariance = DMatrix::<f64>::identity(n, n);
+    let (mut pc, mut ps) = (DVector::zeros(n), DVector::zeros(n));
+    let mut random = rng();
+
+    let mut best = (mean.clone(), f64::INFINITY);
+    let (mut generations, mut evaluations) = (0, 0);
+
+    while evaluations + lambda <= budget {
+        let eigen = SymmetricEigen::new(covariance.clone());
+        let d = eigen.eigenvalues.map(|e| e.max(0.0).sqrt());
+        let b = eigen.eigenvectors;
+
+        if sigma * d.max() < eps || d.max() > 1e7 * d.min() {
+            break;
+        }
+
+        let mut offspring: Vec<(DVector<f64>, f64)> = (0..lambda)
+            .map(|_| {
+                let z = DVector::from_fn(n, |_, _| normal(&mut random));
+                let y = &b * z.component_mul(&d);
+                let x = &mean + sigma * &y;
+                let fx = f(&x);
+                (y, fx)
+            })
+            .collect();
+        offspring.sort_by_key(|(_, fx)| OrderedFloat(*fx));
+        evaluations += lambda;
+        generations += 1;
+
+        if offspring[0].1 < best.1 {
+            best = (&mean + sigma * &offspring[0].0, offspring[0].1);
+        }
+
+        let y_w = (0..mu).fold(DVector::zeros(n), |acc, i| {
+            acc + weights[i] * &offspring[i].0
+        });
+        mean += sigma * &y_w;
+
+        // C^(-1/2) y_w
+        let whitened = &b * (b.tr_mul(&y_w)).component_div(&d.map(|d| d.max(f64::MIN_POSITIVE)));
+        ps = (1.0 - cs) * ps + (cs * (2.0 - cs) * mu_eff).sqrt() * whitened;
+        let h_sigma = ps.norm() / (1.0 - (1.0 - cs).powi(2 * generations as i32)).sqrt() / chi_n
+            < 1.4 + 2.0 / (nf + 1.0);
+        let h_sigma = if h_sigma { 1.0 } else { 0.0 };
+        pc = (1.0 - cc) * pc + h_sigma * (cc * (2.0 - cc) * mu_eff).sqrt() * &y_w;
+
+        let rank_mu = (0..mu).fold(DMatrix::zeros(n, n), |acc, i| {
+            let y = &offspring[i].0;
+            acc + weights[i] * y * y.transpose()
+        });
+        covariance = (1.0 - c1 - c_mu) * covariance.clone()
+            + c1 * (&pc * pc.transpose() + (1.0 - h_sigma) * cc * (2.0 - cc) * covariance)
+            + c_mu * rank_mu;
+
+        sigma *= (cs / damps * (ps.norm() / chi_n - 1.0)).exp();
+
+        // Flat fitness, the samples no longer tell the points apart
+        if offspring[0].1 == offspring[(7 * lambda).div_ceil(10) - 1].1 {
+            break;
+        }
+    }
+
+    Run {
+        x: best.0,
+        f: best.1,
+        generations,
+        evaluations,
+        sigma,
+        covariance,
+    }
+}
+
+impl CmaEs<DVector<f64>> {
+    fn optimize_dynamic(
+        &self,
+        mut f: impl FnMut(&DVector<f64>) -> f64,
+    ) -> (DVector<f64>, f64, CmaInfo) {
+        let n = self.start.len();
+        let default = self.lambda.unwrap_or(4 + (3.0 * (n as f64).ln()) as usize);
+        let restarts = match self.restarts {
+            Restarts::None => 0,
+            Restarts::Ipop { restarts } | Restarts::Bipop { restarts } => restarts,
+        };
+        let mut random = rng();
+
+        let mut best = run(
+            self.start.clone(),
+            self.sigma,
+            default,
+            self.eps,
+            self.max_evaluations,
+            &mut f,
+        );
+        if best.evaluations == 0 && self.max_evaluations > 0 {
+            // The budget did not fit a single generation, the start is the only point to report
+            best.f = f(&best.x);
+            best.evaluations = 1;
+        }
+        let (mut generations, mut evaluations) = (best.generations, best.evaluations);
+        let (mut large, mut large_budget, mut small_budget) = (default, 0, 0);
+        let (mut doublings, mut r) = (0, 0);
+
+        while doublings < restarts && evaluations < self.max_evaluations {
+            let start = self
+                .start
+                .map(|x| x + self.sigma * random.random_range(-1.0..=1.0));
+            let small = matches!(self.restarts, Restarts::Bipop { .. })
+                && doublings > 0
+                && small_budget < large_budget;
+
+            let (lambda, sigma) = if small {
+                let u: f64 = random.random();
+                let ratio = large as f64 / default as f64;
+                let lambda = (default as f64 * ratio.powf(u * u)) as usize;
+                (
+                    lambda,
+                    self.sigma * 10f64.powf(-2.0 * random.random::<f64>()),
+                )
+            } else {
+                large *= 2;
+                doublings += 1;
+                (large, self.sigma)
+            };
+            if self.max_evaluations - evaluations < lambda {
+                break;
+            }
+
+            let next = run(
+                start,
+                sigma,
+                lambda,
+                self.eps,
+                self.max_evaluations - evaluations,
+                &mut f,
+            );
+            if next.evaluations == 0 {
+                break;
+            }
+            if small {
+                small_budget += next.evaluations;
+            } else {
+                large_budget += next.evaluations;
+            }
+            generations += next.generations;
+            evaluations += next.evaluations;
+            if next.f < best.f {
+                best = next;
+            }
+            r += 1;
+        }
+
+        let info = CmaInfo {
+            generations,
+            evaluations,
+            restarts: r,
+            sigma: best.sigma,
+            covariance: best.covariance,
+        };
+        (best.x, best.f, info)
+    }
+}
+
+impl Optimizer for CmaEs<DVector<f64>> {
+    type X = DVector<f64>;
+    type F = f64;
+    type Metadata = CmaInfo;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        self.optimize_dynamic(|x| f(x.clone()))
+    }
+}
+
+impl<const N: usize> Optimizer for CmaEs<Point<N>> {
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = CmaInfo;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let dynamic = CmaEs {
+            start: DVector::from_column_slice(self.start.as_slice()),
+            sigma: self.sigma,
+            lambda: self.lambda,
+            restarts: self.restarts,
+            eps: self.eps,
+            max_evaluations: self.max_evaluations,
+        };
+        let (x, fx, info) = dynamic.optimize_dynamic(|x| f(Point::from_column_slice(x.as_slice())));
+
+        (Point::from_column_slice(x.as_slice()), fx, info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cma_es::{CmaEs, Restarts};
+    use crate::functions::{Booth, Function, Himmelblau, Point, Rastrigin, Rosenbrok, Tang};
+    use crate::method::Optimizer;
+    use crate::task::Task;
+    use approx::assert_relative_eq;
+    use nalgebra::DVector;
+    use test_case::test_case;
+
+    #[test_case(Rosenbrok; "rosenbrok")]
+    #[test_case(Booth; "booth")]
+    #[test_case(Himmelblau; "himmelblau")]
+    fn test<F: Function<2>>(f: F) {
+        Task::new(CmaEs::new(Point::<2>::new(-1.2, 1.0), 1.0, 1e-10), f)
+            .solve_space_check()
+            .check();
+    }
+
+    #[test_case(Restarts::Ipop { restarts: 8 }; "ipop")]
+    #[test_case(Restarts::Bipop { restarts: 8 }; "bipop")]
+    fn test_rastrigin(restarts: Restarts) {
+        Task::new(
+            CmaEs::new(DVector::from_element(5, 3.0), 5.0, 1e-10).with_restarts(restarts),
+            Rastrigin,
+        )
+        .solve_space_check::<5>()
+        .check();
+    }
+
+    #[test]
+    fn test_tang() {
+        Task::new(
+            CmaEs::new(Point::<3>::zeros(), 3.0, 1e-10)
+                .with_restarts(Restarts::Ipop { restarts: 5 }),
+            Tang,
+        )
+        .solve_space_check()
+        .with_eps_y(1e-4)
+        .check();
+    }
+
+    #[test]
+    fn test_ellipsoid_covariance() {
+        let n = 10;
+        let scales = DVector::from_fn(n, |i, _| 10f64.powf(3.0 * i as f64 / (n - 1) as f64));
+        let f = |x: DVector<f64>| x.component_mul(&scales).norm_squared();
+
+        let (x, y, info) = CmaEs::new(DVector::from_element(n, 1.0), 1.0, 1e-12).optimize(f);
+
+        assert_relative_eq!(x, DVector::zeros(n), epsilon = 1e-8);
+        assert!(y < 1e-16);
+        // The learned covariance approximates the inverse Hessian up to scale
+        let c = info.covariance.diagonal();
+        assert!(c[0] / c[n - 1] > 1e4, "{c}");
+    }
+
+    #[test]
+    fn test_bipop_small_budgets() {
+        for budget in (100..=3000).step_by(67) {
+            let optimizer = CmaEs::new(DVector::from_element(5, 3.0), 5.0, 1e-10)
+                .with_restarts(Restarts::Bipop { restarts: 8 })
+                .with_max_evaluations(budget);
+            let (_, _, info) = optimizer.optimize(|x: DVector<f64>| {
+                Rastrigin::f(Point::<5>::from_column_slice(x.as_slice()))
+            });
+
+            assert!(info.evaluations <= budget);
+        }
+    }
+
+    #[test]
+    fn test_budget_below_lambda() {
+        let (x, y, info) = CmaEs::new(Point::<2>::new(1.0, 1.0), 1.0, 1e-10)
+            .with_max_evaluations(5)
+            .optimize(Booth::f);
+
+        assert_eq!(info.evaluations, 1);
+        assert_eq!(y, Booth::f(x));
+    }
+}
@@ -1,14 +1,71 @@
 use crate::functions::Point;
 use crate::method::{OneDimensionalMethod, Optimizer, Steps};
-use crate::zeidel::GaussZeidel;
-use derive_more::Constructor;
+use nalgebra::DMatrix;
 
-#[derive(Constructor, Clone)]
+/// How the direction set is updated after a cycle.
+#[derive(Clone, Copy, Debug)]
+pub enum DirectionUpdate {
+    /// Always replace the direction of largest decrease with the new one, skipping the directions
+    /// added since the last full round, and reset to the axes once the set degenerates. Minimizes
+    /// an N-dimensional quadratic in N cycles.
+    Classic,
+    /// Skip the replacement when it is unlikely to pay off (the test from Numerical Recipes).
+    /// Usually saves line searches but gives up the guarantee.
+    Modified,
+}
+
+/// Powell's conjugate directions method.
+#[derive(Clone)]
 pub struct ConjugateDirections<const N: usize> {
     start: Point<N>,
     optimizer: OneDimensionalMethod,
     eps_x: f64,
     eps_y: f64,
+    update: DirectionUpdate,
+}
+
+impl<const N: usize> ConjugateDirections<N> {
+    pub fn new(start: Point<N>, optimizer: OneDimensionalMethod, eps_x: f64, eps_y: f64) -> Self {
+        Self {
+            start,
+            optimizer,
+            eps_x,
+            eps_y,
+            update: DirectionUpdate::Classic,
+        }
+    }
+
+    pub fn with_update(self, update: DirectionUpdate) -> Self {
+        Self { update, ..self }
+    }
+
+    /// Normalized determinant of the direction set below which it is considered degenerate.
+    const DEPENDENCE: f64 = 1e-8;
+
+    fn line_search(
+        &self,
+        f: &mut impl FnMut(Point<N>) -> f64,
+        x: Point<N>,
+        direction: &Point<N>,
+    ) -> (Point<N>, f64) {
+        let step = |lambda: f64| x + lambda * direction;
+        let (lambda, f_lambda, _) = self.optimizer.optimize(|lambda| f(step(lambda)));
+
+        (step(lambda), f_lambda)
+    }
+
+    fn axes() -> Vec<Point<N>> {
+        (0..N)
+            .map(|i| Point::from_fn(|j, _| if i == j { 1.0 } else { 0.0 }))
+            .collect()
+    }
+
+    fn is_dependent(directions: &[Point<N>]) -> bool {
+        DMatrix::from_fn(N, N, |i, j| directions[j][i])
+            .determinant()
+            .abs()
+            < Self::DEPENDENCE
+    }
 }
 
 impl<const N: usize> Optimizer for ConjugateDirections<N> {
@@ -20,39 +77,87 @@ impl<const N: usize> Optimizer for ConjugateDirections<N> {
         &self,
         mut f: impl FnMut(Self::X) -> Self::F,
     ) -> (Self::X, Self::F, Self::Metadata) {
+        let mut directions = Self::axes();
         let mut r = 0;
         let mut x = self.start;
+        let mut fx = f(x);
 
-        loop {
-            let gauss = GaussZeidel::new(x, self.optimizer.clone(), self.eps_x, self.eps_y);
-            let x0 = gauss.step(&mut f, 0, &x);
+        // Directions added by the previous cycles sit at the end of the set and are mutually
+        // conjugate on a quadratic, so only the ones before them compete for replacement.
+        let mut conjugate = 0;
 
-            let gauss = GaussZeidel::new(x0, self.optimizer.clone(), self.eps_x, self.eps_x);
-            let xn = gauss.full_step(&mut f, x0);
+        loop {
+            let (x0, f0) = (x, fx);
+            let candidates = match self.update {
+                DirectionUpdate::Classic => N - conjugate,
+                DirectionUpdate::Modified => N,
+            };
+            let mut largest = (0, 0.0);
 
-            let p = xn - x0;
-            let step = |lambda: f64| x0 + lambda * p;
-            let (lambda, _, _) = self.optimizer.optimize(|lambda| f(step(lambda)));
+            for (i, direction) in directions.iter().enumerate() {
+                let (next, f_next) = self.line_search(&mut f, x, direction);
+                if i < candidates && fx - f_next > largest.1 {
+                    largest = (i, fx - f_next);
+                }
+                (x, fx) = (next, f_next);
+            }
+            r += 1;
 
-            let x_ = x;
-            x = step(lambda);
-            if (f(x) - f(x_)).abs() < self.eps_y || (x - x_).norm() < self.eps_x {
+            if (f0 - fx).abs() < self.eps_y || (x - x0).norm() < self.eps_x {
                 break;
             }
 
-            r += 1;
+            let (i, delta) = largest;
+            let p = (x - x0).normalize();
+
+            match self.update {
+                DirectionUpdate::Classic => {
+                    (x, fx) = self.line_search(&mut f, x, &p);
+
+                    directions.remove(i);
+                    directions.push(p);
+                    conjugate = (conjugate + 1) % N;
+                    if Self::is_dependent(&directions) {
+                        directions = Self::axes();
+                        conjugate = 0;
+                    }
+                }
+                DirectionUpdate::Modified => {
+                    let fe = f(2.0 * x - x0);
+
+                    if fe >= f0
+                        || 2.0 * (f0 - 2.0 * fx + fe) * (f0 - fx - delta).powi(2)
+                            >= delta * (f0 - fe).powi(2)
+                    {
+                        continue;
+                    }
+
+                    (x, fx) = self.line_search(&mut f, x, &p);
+
+                    let replaced = directions[i];
+                    directions.remove(i);
+                    directions.push(p);
+                    if Self::is_dependent(&directions) {
+                        directions.pop();
+                        directions.insert(i, replaced);
+                    }
+                }
+            }
         }
 
-        (x, f(x), Steps(r))
+        (x, fx, Steps(r))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::conjugate_directions::ConjugateDirections;
+    use crate::brent::Brent;
+    use crate::conjugate_directions::{ConjugateDirections, DirectionUpdate};
     use crate::def_test;
-    use crate::fibonacci::GoldenRatio;
+    use crate::fibonacci::{Fibonacci, GoldenRatio};
     use crate::functions::{Booth, Himmelblau, Sphere};
+    use crate::functions::{Function, Point};
+    use crate::method::{Optimizer, Steps};
     use crate::task::Task;
     use std::sync::LazyLock;
 
@@ -84,4 +189,70 @@ mod tests {
             .solve_space_check()
             .check();
     }
+
+    #[test]
+    fn test_conjugate_dirs_himmelblau_modified() {
+        Task::new(
+            OPTIMIZER.clone().with_update(DirectionUpdate::Modified),
+            Himmelblau,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test]
+    fn test_conjugate_dirs_booth_fibonacci() {
+        Task::new(
+            ConjugateDirections::new(
+                [-2.0, -5.0].into(),
+                Fibonacci::new(-10.0..=10.0, 1e-9).into(),
+                1e-9,
+                1e-10,
+            ),
+            Booth,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    struct Quadratic;
+
+    impl Function<3> for Quadratic {
+        const F: f64 = 0.0;
+
+        fn X() -> Vec<Point<3>> {
+            vec![[1.0, -2.0, 0.5].into()]
+        }
+
+        fn f(x: Point<3>) -> f64 {
+            let (a, b, c) = (x[0] - 1.0, x[1] + 2.0, x[2] - 0.5);
+            4.0 * a * a + 3.0 * b * b + 2.0 * c * c + 2.0 * a * b - 2.0 * b * c + a * c
+        }
+    }
+
+    fn quadratic_termination<const N: usize, F: Function<N>>(start: Point<N>) {
+        let optimizer =
+            ConjugateDirections::new(start, Brent::new(-20.0..=20.0, 1e-10).into(), 1e-6, 1e-14);
+        let (x, y, Steps(r)) = optimizer.optimize(F::f);
+
+        // N cycles to build the conjugate set plus one cycle to confirm convergence
+        assert!(r <= N + 1, "Took {r} cycles");
+        approx::assert_relative_eq!(x, F::X()[0], epsilon = 1e-6);
+        approx::assert_abs_diff_eq!(y, F::F, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_conjugate_dirs_quadratic_termination_booth() {
+        quadratic_termination::<2, Booth>([-2.0, -5.0].into());
+    }
+
+    #[test]
+    fn test_conjugate_dirs_quadratic_termination_sphere() {
+        quadratic_termination::<4, Sphere<4>>([-2.0, -5.0, 3.0, 1.0].into());
+    }
+
+    #[test]
+    fn test_conjugate_dirs_quadratic_termination_3d() {
+        quadratic_termination::<3, Quadratic>([5.0, 5.0, 5.0].into());
+    }
 }
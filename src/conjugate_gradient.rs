@@ -0,0 +1,201 @@
+use crate::derivatives::Derivatives;
+use crate::functions::Point;
+use crate::line_search::Search;
+use crate::method::{Optimizer, Steps};
+
+/// Formula for the coefficient mixing the previous direction into the new one.
+#[derive(Debug, Clone, Copy)]
+pub enum Beta {
+    FletcherReeves,
+    PolakRibiere,
+    /// Polak–Ribière clipped at zero, which restarts along the antigradient instead of
+    /// producing a negative coefficient.
+    PolakRibierePlus,
+    HestenesStiefel,
+}
+
+impl Beta {
+    fn compute<const N: usize>(
+        &self,
+        gradient: &Point<N>,
+        previous: &Point<N>,
+        direction: &Point<N>,
+    ) -> f64 {
+        let y = gradient - previous;
+        let beta = match self {
+            Beta::FletcherReeves => gradient.norm_squared() / previous.norm_squared(),
+            Beta::PolakRibiere | Beta::PolakRibierePlus => {
+                gradient.dot(&y) / previous.norm_squared()
+            }
+            Beta::HestenesStiefel => gradient.dot(&y) / direction.dot(&y),
+        };
+
+        match self {
+            Beta::PolakRibierePlus => beta.max(0.0),
+            _ if beta.is_finite() => beta,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Nonlinear conjugate gradient method.
+#[derive(Clone)]
+pub struct ConjugateGradient<const N: usize> {
+    start: Point<N>,
+    derivatives: Derivatives<N>,
+    beta: Beta,
+    search: Search,
+    restart: usize,
+    eps: f64,
+    max_steps: usize,
+}
+
+impl<const N: usize> ConjugateGradient<N> {
+    /// Restarts along the antigradient every `N` iterations.
+    pub fn new(
+        start: Point<N>,
+        derivatives: Derivatives<N>,
+        beta: Beta,
+        search: impl Into<Search>,
+        eps: f64,
+    ) -> Self {
+        Self {
+            start,
+            derivatives,
+            beta,
+            search: search.into(),
+            restart: N,
+            eps,
+            max_steps: 100_000,
+        }
+    }
+
+    pub fn with_restart(self, restart: usize) -> Self {
+        assert!(restart > 0, "The restart period must be positive");
+        Self { restart, ..self }
+    }
+
+    pub fn with_max_steps(self, max_steps: usize) -> Self {
+        Self { max_steps, ..self }
+    }
+}
+
+impl<const N: usize> Optimizer for ConjugateGradient<N> {
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = Steps;
+
+    fn optimize(&self, f: impl FnMut(Self::X) -> Self::F) -> (Self::X, Self::F, Self::Metadata) {
+        let mut objective = self.derivatives.of(f);
+        let mut x = self.start;
+        let (mut fx, mut gradient) = objective.value_gradient(x);
+        let mut direction = -gradient;
+        let mut alpha = 1.0 / gradient.norm().max(1.0);
+        let mut previous_slope = None;
+        let mut r = 0;
+
+        while r < self.max_steps && gradient.norm() >= self.eps {
+            let mut slope = gradient.dot(&direction);
+            if slope >= 0.0 {
+                direction = -gradient;
+                slope = -gradient.norm_squared();
+            }
+
+            let initial = previous_slope.map_or(alpha, |previous| alpha * previous / slope);
+            let (step, f_next) =
+                self.search
+                    .step(&mut objective, x, direction, (fx, slope), initial.min(1.0));
+            if step == 0.0 {
+                break;
+            }
+
+            x += step * direction;
+            fx = f_next;
+            alpha = step;
+            previous_slope = Some(slope);
+
+            let previous = gradient;
+            gradient = objective.gradient(x);
+            r += 1;
+
+            let beta = if r % self.restart == 0 {
+                0.0
+            } else {
+                self.beta.compute(&gradient, &previous, &direction)
+            };
+            direction = -gradient + beta * direction;
+        }
+
+        (x, fx, Steps(r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::brent::Brent;
+    use crate::conjugate_gradient::{Beta, ConjugateGradient};
+    use crate::derivatives::{Derivatives, Difference};
+    use crate::functions::{Booth, Function, GenericFunction, Rosenbrok, Sphere};
+    use crate::line_search::LineSearch;
+    use crate::method::{OneDimensionalMethod, Optimizer, Steps};
+    use crate::task::Task;
+    use test_case::test_case;
+
+    #[test_case(Beta::FletcherReeves, Rosenbrok)]
+    #[test_case(Beta::PolakRibiere, Rosenbrok)]
+    #[test_case(Beta::PolakRibierePlus, Rosenbrok)]
+    #[test_case(Beta::HestenesStiefel, Rosenbrok)]
+    #[test_case(Beta::PolakRibierePlus, Booth)]
+    #[test_case(Beta::PolakRibierePlus, Sphere)]
+    fn test_wolfe<F: GenericFunction<2> + 'static>(beta: Beta, f: F) {
+        Task::new(
+            ConjugateGradient::new(
+                [-1.2, 1.0].into(),
+                Derivatives::automatic::<F>(),
+                beta,
+                LineSearch::strong_wolfe(1e-4, 0.1),
+                1e-10,
+            ),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test_case(Beta::FletcherReeves, Rosenbrok)]
+    #[test_case(Beta::PolakRibierePlus, Rosenbrok)]
+    #[test_case(Beta::FletcherReeves, Booth)]
+    #[test_case(Beta::HestenesStiefel, Sphere)]
+    fn test_exact<F: Function<2>>(beta: Beta, f: F) {
+        let method = OneDimensionalMethod::from(Brent::new(0.0..=1.0, 1e-12));
+
+        Task::new(
+            ConjugateGradient::new(
+                [-1.2, 1.0].into(),
+                Derivatives::Numeric(Difference::Richardson),
+                beta,
+                method.auto_bracket(0.0, 1e-3, 60),
+                1e-7,
+            ),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test]
+    fn test_quadratic_termination() {
+        let method = OneDimensionalMethod::from(Brent::new(0.0..=1.0, 1e-12));
+        let optimizer = ConjugateGradient::new(
+            [-4.0, 2.0, 7.0, 1.0].into(),
+            Derivatives::automatic::<Sphere<4>>(),
+            Beta::FletcherReeves,
+            method.auto_bracket(0.0, 1e-2, 60),
+            1e-8,
+        );
+        let (_, y, Steps(r)) = optimizer.optimize(Sphere::f);
+
+        assert!(r <= 4, "Took {r} iterations");
+        approx::assert_abs_diff_eq!(y, 0.0, epsilon = 1e-12);
+    }
+}
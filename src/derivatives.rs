@@ -0,0 +1,365 @@
+use crate::dual;
+use crate::functions::{GenericFunction, Point};
+use nalgebra::allocator::Allocator;
+use nalgebra::{DefaultAllocator, Dim, OMatrix, OVector, SMatrix};
+use std::rc::Rc;
+
+/// Finite difference scheme. Steps are chosen automatically from the machine epsilon and the
+/// magnitude of the coordinate being perturbed.
+#[derive(Debug, Clone, Copy)]
+pub enum Difference {
+    Forward,
+    Central,
+    /// Central differences with steps `h` and `h / 2` combined to cancel the `h^2` error term.
+    Richardson,
+}
+
+impl Difference {
+    fn gradient_step(&self, x: f64) -> f64 {
+        let h = match self {
+            Difference::Forward => f64::EPSILON.sqrt(),
+            Difference::Central => f64::EPSILON.cbrt(),
+            Difference::Richardson => f64::EPSILON.powf(0.2),
+        };
+        h * x.abs().max(1.0)
+    }
+
+    fn hessian_step(&self, x: f64) -> f64 {
+        let h = match self {
+            Difference::Forward => f64::EPSILON.cbrt(),
+            Difference::Central => f64::EPSILON.powf(0.25),
+            Difference::Richardson => f64::EPSILON.powf(1.0 / 6.0),
+        };
+        h * x.abs().max(1.0)
+    }
+
+    /// Derivative of `g` at zero, `g0 = g(0)` is only used by the forward scheme.
+    fn difference(&self, g: &mut impl FnMut(f64) -> f64, g0: f64, h: f64) -> f64 {
+        let central = |g: &mut dyn FnMut(f64) -> f64, h: f64| (g(h) - g(-h)) / (2.0 * h);
+
+        match self {
+            Difference::Forward => (g(h) - g0) / h,
+            Difference::Central => central(g, h),
+            Difference::Richardson => (4.0 * central(g, h / 2.0) - central(g, h)) / 3.0,
+        }
+    }
+
+    /// Derivative of a scalar function at `x`.
+    pub fn derivative(&self, mut f: impl FnMut(f64) -> f64, x: f64) -> f64 {
+        let g0 = match self {
+            Difference::Forward => f(x),
+            _ => f64::NAN,
+        };
+        self.difference(&mut |t| f(x + t), g0, self.gradient_step(x))
+    }
+
+    pub fn gradient<D: Dim>(
+        &self,
+        mut f: impl FnMut(OVector<f64, D>) -> f64,
+        x: &OVector<f64, D>,
+    ) -> OVector<f64, D>
+    where
+        DefaultAllocator: Allocator<D>,
+    {
+        let f0 = match self {
+            Difference::Forward => f(x.clone()),
+            _ => f64::NAN,
+        };
+
+        let mut gradient = x.clone();
+        for i in 0..x.len() {
+            let mut g = |t| {
+                let mut y = x.clone();
+                y[i] += t;
+                f(y)
+            };
+            gradient[i] = self.difference(&mut g, f0, self.gradient_step(x[i]));
+        }
+        gradient
+    }
+
+    /// Jacobian `J[(i, j)] = d f_i / d x_j` of a vector function.
+    pub fn jacobian<D: Dim, M: Dim>(
+        &self,
+        mut f: impl FnMut(OVector<f64, D>) -> OVector<f64, M>,
+        x: &OVector<f64, D>,
+    ) -> OMatrix<f64, M, D>
+    where
+        DefaultAllocator: Allocator<D> + Allocator<M> + Allocator<M, D>,
+    {
+        let f0 = f(x.clone());
+        let mut jacobian = OMatrix::zeros_generic(f0.shape_generic().0, x.shape_generic().0);
+        for j in 0..x.len() {
+            let mut g = |t| {
+                let mut y = x.clone();
+                y[j] += t;
+                f(y)
+            };
+            let central =
+                |g: &mut dyn FnMut(f64) -> OVector<f64, M>, h: f64| (g(h) - g(-h)) / (2.0 * h);
+
+            let h = self.gradient_step(x[j]);
+            let column = match self {
+                Difference::Forward => (g(h) - &f0) / h,
+                Difference::Central => central(&mut g, h),
+                Difference::Richardson => {
+                    (central(&mut g, h / 2.0) * 4.0 - central(&mut g, h)) / 3.0
+                }
+            };
+            jacobian.set_column(j, &column);
+        }
+        jacobian
+    }
+
+    pub fn hessian<D: Dim>(
+        &self,
+        mut f: impl FnMut(OVector<f64, D>) -> f64,
+        x: &OVector<f64, D>,
+    ) -> OMatrix<f64, D, D>
+    where
+        DefaultAllocator: Allocator<D> + Allocator<D, D>,
+    {
+        let (n, _) = x.shape_generic();
+        let h = x.map(|x| self.hessian_step(x));
+        let mut at = |i: usize, di: f64, j: usize, dj: f64| {
+            let mut y = x.clone();
+            y[i] += di;
+            y[j] += dj;
+            f(y)
+        };
+
+        let mut hessian = OMatrix::zeros_generic(n, n);
+        for i in 0..x.len() {
+            for j in i..x.len() {
+                let central = |at: &mut dyn FnMut(usize, f64, usize, f64) -> f64, hi, hj| {
+                    (at(i, hi, j, hj) - at(i, hi, j, -hj) - at(i, -hi, j, hj) + at(i, -hi, j, -hj))
+                        / (4.0 * hi * hj)
+                };
+
+                let (hi, hj) = (h[i], h[j]);
+                hessian[(i, j)] = match self {
+                    Difference::Forward => {
+                        (at(i, hi, j, hj) - at(i, hi, j, 0.0) - at(i, 0.0, j, hj)
+                            + at(i, 0.0, j, 0.0))
+                            / (hi * hj)
+                    }
+                    Difference::Central => central(&mut at, hi, hj),
+                    Difference::Richardson => {
+                        (4.0 * central(&mut at, hi / 2.0, hj / 2.0) - central(&mut at, hi, hj))
+                            / 3.0
+                    }
+                };
+                hessian[(j, i)] = hessian[(i, j)];
+            }
+        }
+        hessian
+    }
+}
+
+pub type GradientFn<const N: usize> = Rc<dyn Fn(Point<N>) -> Point<N>>;
+pub type HessianFn<const N: usize> = Rc<dyn Fn(Point<N>) -> SMatrix<f64, N, N>>;
+
+/// Source of derivatives for gradient based methods.
+#[derive(Clone)]
+pub enum Derivatives<const N: usize> {
+    Numeric(Difference),
+    /// Analytic gradient, the Hessian is obtained by differencing it when not given.
+    Exact {
+        gradient: GradientFn<N>,
+        hessian: Option<HessianFn<N>>,
+    },
+}
+
+impl<const N: usize> Derivatives<N> {
+    pub fn exact(gradient: impl Fn(Point<N>) -> Point<N> + 'static) -> Self {
+        Derivatives::Exact {
+            gradient: Rc::new(gradient),
+            hessian: None,
+        }
+    }
+
+    /// Exact gradient and Hessian by forward mode automatic differentiation.
+    pub fn automatic<F: GenericFunction<N> + 'static>() -> Self {
+        Derivatives::Exact {
+            gradient: Rc::new(dual::gradient::<N, F>),
+            hessian: Some(Rc::new(dual::hessian::<N, F>)),
+        }
+    }
+
+    pub fn with_hessian(self, hessian: impl Fn(Point<N>) -> SMatrix<f64, N, N> + 'static) -> Self {
+        match self {
+            Derivatives::Exact { gradient, .. } => Derivatives::Exact {
+                gradient,
+                hessian: Some(Rc::new(hessian)),
+            },
+            numeric => numeric,
+        }
+    }
+
+    /// Wraps a plain objective so that it also yields derivatives.
+    pub fn of<F: FnMut(Point<N>) -> f64>(&self, f: F) -> Objective<'_, N, F> {
+        Objective {
+            f,
+            derivatives: self,
+            evaluations: 0,
+            gradient_evaluations: 0,
+        }
+    }
+}
+
+/// Objective bundled with its derivatives. Counts calls of the function, including those made
+/// by finite differences, and of the gradient.
+pub struct Objective<'a, const N: usize, F> {
+    f: F,
+    derivatives: &'a Derivatives<N>,
+    pub evaluations: usize,
+    pub gradient_evaluations: usize,
+}
+
+impl<const N: usize, F: FnMut(Point<N>) -> f64> Objective<'_, N, F> {
+    pub fn value(&mut self, x: Point<N>) -> f64 {
+        self.evaluations += 1;
+        (self.f)(x)
+    }
+
+    pub fn gradient(&mut self, x: Point<N>) -> Point<N> {
+        self.gradient_evaluations += 1;
+        match self.derivatives {
+            Derivatives::Numeric(difference) => {
+                let f = &mut self.f;
+                let evaluations = &mut self.evaluations;
+                difference.gradient(
+                    |x| {
+                        *evaluations += 1;
+                        f(x)
+                    },
+                    &x,
+                )
+            }
+            Derivatives::Exact { gradient, .. } => gradient(x),
+        }
+    }
+
+    pub fn value_gradient(&mut self, x: Point<N>) -> (f64, Point<N>) {
+        (self.value(x), self.gradient(x))
+    }
+
+    pub fn hessian(&mut self, x: Point<N>) -> SMatrix<f64, N, N> {
+        match self.derivatives {
+            Derivatives::Numeric(difference) => {
+                let f = &mut self.f;
+                let evaluations = &mut self.evaluations;
+                difference.hessian(
+                    |x| {
+                        *evaluations += 1;
+                        f(x)
+                    },
+                    &x,
+                )
+            }
+            Derivatives::Exact {
+                hessian: Some(hessian),
+                ..
+            } => hessian(x),
+            Derivatives::Exact { gradient, .. } => {
+                self.gradient_evaluations += 2 * N;
+                let h = x.map(|x| Difference::Central.gradient_step(x));
+                let columns = SMatrix::<f64, N, N>::from_fn(|i, j| {
+                    let mut forward = x;
+                    let mut backward = x;
+                    forward[j] += h[j];
+                    backward[j] -= h[j];
+                    (gradient(forward)[i] - gradient(backward)[i]) / (2.0 * h[j])
+                });
+                (columns + columns.transpose()) / 2.0
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::derivatives::{Derivatives, Difference};
+    use crate::functions::{Function, Point, Rosenbrok};
+    use approx::assert_relative_eq;
+    use nalgebra::{DVector, SMatrix};
+    use test_case::test_case;
+
+    fn rosenbrok_gradient(x: Point<2>) -> Point<2> {
+        [
+            -400.0 * x[0] * (x[1] - x[0].powi(2)) - 2.0 * (1.0 - x[0]),
+            200.0 * (x[1] - x[0].powi(2)),
+        ]
+        .into()
+    }
+
+    fn rosenbrok_hessian(x: Point<2>) -> SMatrix<f64, 2, 2> {
+        SMatrix::<f64, 2, 2>::new(
+            1200.0 * x[0].powi(2) - 400.0 * x[1] + 2.0,
+            -400.0 * x[0],
+            -400.0 * x[0],
+            200.0,
+        )
+    }
+
+    #[test_case(Difference::Forward, 1e-4)]
+    #[test_case(Difference::Central, 1e-7)]
+    #[test_case(Difference::Richardson, 1e-9)]
+    fn test_gradient(difference: Difference, eps: f64) {
+        let x: Point<2> = [-1.2, 1.0].into();
+        let gradient = difference.gradient(Rosenbrok::f, &x);
+
+        assert_relative_eq!(gradient, rosenbrok_gradient(x), max_relative = eps);
+    }
+
+    #[test_case(Difference::Forward, 1e-4)]
+    #[test_case(Difference::Central, 1e-6)]
+    #[test_case(Difference::Richardson, 1e-7)]
+    fn test_hessian(difference: Difference, eps: f64) {
+        let x: Point<2> = [-1.2, 1.0].into();
+        let hessian = difference.hessian(Rosenbrok::f, &x);
+
+        assert_relative_eq!(hessian, rosenbrok_hessian(x), max_relative = eps);
+    }
+
+    #[test_case(Difference::Forward, 1e-6)]
+    #[test_case(Difference::Central, 1e-9)]
+    #[test_case(Difference::Richardson, 1e-10)]
+    fn test_jacobian(difference: Difference, eps: f64) {
+        let x: Point<2> = [-1.2, 1.0].into();
+        let jacobian = difference.jacobian(rosenbrok_gradient, &x);
+
+        assert_relative_eq!(jacobian, rosenbrok_hessian(x), max_relative = eps);
+    }
+
+    #[test]
+    fn test_dynamic_gradient() {
+        let x = DVector::from_fn(100, |i, _| i as f64 / 10.0);
+        let gradient = Difference::Central.gradient(|x: DVector<f64>| x.norm_squared(), &x);
+
+        assert_relative_eq!(gradient, 2.0 * x, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_objective() {
+        let x: Point<2> = [0.5, -0.3].into();
+        let numeric = Derivatives::Numeric(Difference::Central);
+        let exact = Derivatives::exact(rosenbrok_gradient);
+
+        let mut objective = numeric.of(Rosenbrok::f);
+        let (f, gradient) = objective.value_gradient(x);
+        assert_eq!(f, Rosenbrok::f(x));
+        assert_relative_eq!(gradient, rosenbrok_gradient(x), max_relative = 1e-7);
+        assert_eq!(objective.evaluations, 5);
+        assert_eq!(objective.gradient_evaluations, 1);
+
+        let mut objective = exact.of(Rosenbrok::f);
+        assert_eq!(objective.gradient(x), rosenbrok_gradient(x));
+        assert_relative_eq!(
+            objective.hessian(x),
+            rosenbrok_hessian(x),
+            max_relative = 1e-7
+        );
+        assert_eq!(objective.evaluations, 0);
+    }
+}
@@ -0,0 +1,358 @@
+use crate::functions::Point;
+use crate::method::{Evaluations, Optimizer, Steps};
+use crate::utils::{cauchy, normal};
+use nalgebra::SVector;
+use ordered_float::OrderedFloat;
+use rand::seq::index::sample;
+use rand::{RngExt, rng};
+use std::ops::RangeInclusive;
+
+/// Mutation and crossover scheme, named `DE/base/differences/crossover`.
+#[derive(Debug, Clone, Copy)]
+pub enum Strategy {
+    /// `v = x_r1 + F (x_r2 - x_r3)`, binomial crossover.
+    Rand1Bin,
+    /// `v = x_best + F (x_r1 - x_r2)`, binomial crossover.
+    Best1Bin,
+    /// `v = x_i + F (x_best - x_i) + F (x_r1 - x_r2)`, binomial crossover.
+    CurrentToBest1,
+    /// `v = x_r1 + F (x_r2 - x_r3) + F (x_r4 - x_r5)`, exponential crossover.
+    Rand2Exp,
+}
+
+impl Strategy {
+    fn mutant<const N: usize>(
+        &self,
+        population: &[Point<N>],
+        i: usize,
+        best: usize,
+        f: f64,
+    ) -> Point<N> {
+        let r: Vec<_> = sample(&mut rng(), population.len(), 6)
+            .into_iter()
+            .filter(|&r| r != i)
+            .map(|r| population[r])
+            .collect();
+
+        match self {
+            Strategy::Rand1Bin => r[0] + f * (r[1] - r[2]),
+            Strategy::Best1Bin => population[best] + f * (r[0] - r[1]),
+            Strategy::CurrentToBest1 => {
+                let x = population[i];
+                x + f * (population[best] - x) + f * (r[0] - r[1])
+            }
+            Strategy::Rand2Exp => r[0] + f * (r[1] - r[2]) + f * (r[3] - r[4]),
+        }
+    }
+
+    fn crossover<const N: usize>(&self, x: &Point<N>, mutant: &Point<N>, cr: f64) -> Point<N> {
+        let mut random = rng();
+        let mut trial = *x;
+        let start = random.random_range(0..N);
+
+        match self {
+            Strategy::Rand2Exp => {
+                // Contiguous run of coordinates starting at a random one
+                for j in (0..N).map(|j| (start + j) % N) {
+                    trial[j] = mutant[j];
+                    if random.random::<f64>() >= cr {
+                        break;
+                    }
+                }
+            }
+            _ => {
+                for j in 0..N {
+                    if j == start || random.random::<f64>() < cr {
+                        trial[j] = mutant[j];
+                    }
+                }
+            }
+        }
+        trial
+    }
+}
+
+/// Choice of the scale factor `F` and the crossover rate `CR`.
+#[derive(Debug, Clone, Copy)]
+pub enum Control {
+    Fixed {
+        f: f64,
+        cr: f64,
+    },
+    /// jDE: every individual carries its own parameters, resampled with probabilities `tau_f`
+    /// and `tau_cr` and kept while they produce successful trials.
+    Jde {
+        tau_f: f64,
+        tau_cr: f64,
+    },
+    /// SHADE: parameters are sampled around means kept in a circular memory of `memory` entries,
+    /// updated from the parameters of successful trials weighted by their improvement.
+    Shade {
+        memory: usize,
+    },
+}
+
+/// Parameters of one individual.
+#[derive(Debug, Clone, Copy)]
+struct Parameters {
+    f: f64,
+    cr: f64,
+}
+
+/// Success history of SHADE.
+struct History {
+    f: Vec<f64>,
+    cr: Vec<f64>,
+    next: usize,
+}
+
+impl History {
+    fn new(memory: usize) -> Self {
+        Self {
+            f: vec![0.5; memory],
+            cr: vec![0.5; memory],
+            next: 0,
+        }
+    }
+
+    fn sample(&self) -> Parameters {
+        let mut random = rng();
+        let k = random.random_range(0..self.f.len());
+        let cr = (self.cr[k] + 0.1 * normal(&mut random)).clamp(0.0, 1.0);
+        let f = loop {
+            let f = self.f[k] + 0.1 * cauchy(&mut random);
+            if f > 0.0 {
+                break f.min(1.0);
+            }
+        };
+        Parameters { f, cr }
+    }
+
+    /// Records the parameters of successful trials with their improvements.
+    fn update(&mut self, successes: &[(Parameters, f64)]) {
+        let total: f64 = successes.iter().map(|(_, w)| w).sum();
+        if successes.is_empty() || total <= 0.0 {
+            return;
+        }
+
+        let weighted = |g: fn(&Parameters) -> f64| {
+            successes.iter().map(|(p, w)| w / total * g(p)).sum::<f64>()
+        };
+        self.cr[self.next] = weighted(|p| p.cr);
+        // Lehmer mean biases F towards larger successful values
+        self.f[self.next] = weighted(|p| p.f * p.f) / weighted(|p| p.f);
+        self.next = (self.next + 1) % self.f.len();
+    }
+}
+
+/// Differential evolution over a box. Stops when the spread of the values in the population
+/// falls below `eps`.
+#[derive(Clone)]
+pub struct DifferentialEvolution<const N: usize> {
+    bounds: SVector<RangeInclusive<f64>, N>,
+    population: usize,
+    strategy: Strategy,
+    control: Control,
+    eps: f64,
+    max_generations: usize,
+}
+
+impl<const N: usize> DifferentialEvolution<N> {
+    pub fn new(
+        bounds: [RangeInclusive<f64>; N],
+        population: usize,
+        strategy: Strategy,
+        eps: f64,
+    ) -> Self {
+        assert!(population >= 6, "population must have at least 6 members");
+        Self {
+            bounds: bounds.into(),
+            population,
+            strategy,
+            control: Control::Fixed { f: 0.5, cr: 0.9 },
+            eps,
+            max_generations: 1000 * N,
+        }
+    }
+
+    pub fn with_control(self, control: Control) -> Self {
+        match control {
+            Control::Fixed { f, cr } => {
+                assert!(f > 0.0 && f <= 2.0, "F must lie in (0, 2]");
+                assert!((0.0..=1.0).contains(&cr), "CR must lie in [0, 1]");
+            }
+            Control::Jde { tau_f, tau_cr } => assert!(
+                (0.0..=1.0).contains(&tau_f) && (0.0..=1.0).contains(&tau_cr),
+                "jDE resampling probabilities must lie in [0, 1]"
+            ),
+            Control::Shade { memory } => assert!(memory > 0, "SHADE needs a non-empty memory"),
+        }
+        Self { control, ..self }
+    }
+
+    pub fn with_max_generations(self, max_generations: usize) -> Self {
+        Self {
+            max_generations,
+            ..self
+        }
+    }
+
+    /// Coordinates outside the box are moved halfway between the parent and the violated bound.
+    fn repair(&self, trial: Point<N>, parent: &Point<N>) -> Point<N> {
+        Point::from_fn(|j, _| {
+            let (lo, hi) = (*self.bounds[j].start(), *self.bounds[j].end());
+            match trial[j] {
+                t if t < lo => (parent[j] + lo) / 2.0,
+                t if t > hi => (parent[j] + hi) / 2.0,
+                t => t,
+            }
+        })
+    }
+}
+
+impl<const N: usize> Optimizer for DifferentialEvolution<N> {
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = (Steps, Evaluations);
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let mut random = rng();
+        let map_to = |x, y: RangeInclusive<f64>| (y.end() - y.start()) * x + y.start();
+
+        let mut population: Vec<Point<N>> = (0..self.population)
+            .map(|_| Point::<N>::new_random().zip_map(&self.bounds, map_to))
+            .collect();
+        let mut values: Vec<f64> = population.iter().map(|x| f(*x)).collect();
+        let mut evaluations = self.population;
+
+        let mut parameters = vec![Parameters { f: 0.5, cr: 0.9 }; self.population];
+        let mut history = match self.control {
+            Control::Shade { memory } => Some(History::new(memory)),
+            _ => None,
+        };
+        let mut r = 0;
+
+        let spread = |values: &[f64]| {
+            let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+            max - min
+        };
+
+        while r < self.max_generations && spread(&values) >= self.eps {
+            let best = (0..self.population)
+                .min_by_key(|&i| OrderedFloat(values[i]))
+                .unwrap();
+            let mut successes = vec![];
+
+            for i in 0..self.population {
+                let p = match (self.control, &history) {
+                    (Control::Fixed { f, cr }, _) => Parameters { f, cr },
+                    (Control::Jde { tau_f, tau_cr }, _) => Parameters {
+                        f: if random.random::<f64>() < tau_f {
+                            0.1 + 0.9 * random.random::<f64>()
+                        } else {
+                            parameters[i].f
+                        },
+                        cr: if random.random::<f64>() < tau_cr {
+                            random.random()
+                        } else {
+                            parameters[i].cr
+                        },
+                    },
+                    (Control::Shade { .. }, Some(history)) => history.sample(),
+                    (Control::Shade { .. }, None) => unreachable!(),
+                };
+
+                let mutant = self.strategy.mutant(&population, i, best, p.f);
+                let trial = self.strategy.crossover(&population[i], &mutant, p.cr);
+                let trial = self.repair(trial, &population[i]);
+                let value = f(trial);
+                evaluations += 1;
+
+                if value <= values[i] {
+                    successes.push((p, values[i] - value));
+                    population[i] = trial;
+                    values[i] = value;
+                    parameters[i] = p;
+                }
+            }
+
+            if let Some(history) = &mut history {
+                history.update(&successes);
+            }
+            r += 1;
+        }
+
+        let best = (0..self.population)
+            .min_by_key(|&i| OrderedFloat(values[i]))
+            .unwrap();
+        (
+            population[best],
+            values[best],
+            (Steps(r), Evaluations(evaluations)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::differential_evolution::{Control, DifferentialEvolution, Strategy};
+    use crate::functions::{Booth, Function, Himmelblau, Rastrigin, Rosenbrok, Tang};
+    use crate::task::Task;
+    use test_case::test_case;
+
+    #[test_case(Strategy::Rand1Bin; "rand_1_bin")]
+    #[test_case(Strategy::CurrentToBest1; "current_to_best_1")]
+    #[test_case(Strategy::Rand2Exp; "rand_2_exp")]
+    fn test_rastrigin(strategy: Strategy) {
+        Task::new(
+            DifferentialEvolution::new([-5.12..=5.12, -5.12..=5.12], 40, strategy, 1e-12),
+            Rastrigin,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test_case(Booth)]
+    #[test_case(Himmelblau)]
+    fn test_best<F: Function<2>>(f: F) {
+        Task::new(
+            DifferentialEvolution::new([-10.0..=10.0, -10.0..=10.0], 30, Strategy::Best1Bin, 1e-12),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test_case(Control::Jde { tau_f: 0.1, tau_cr: 0.1 }, Rosenbrok; "jde_rosenbrok")]
+    #[test_case(Control::Jde { tau_f: 0.1, tau_cr: 0.1 }, Himmelblau; "jde_himmelblau")]
+    #[test_case(Control::Shade { memory: 5 }, Rosenbrok; "shade_rosenbrok")]
+    #[test_case(Control::Shade { memory: 5 }, Booth; "shade_booth")]
+    fn test_adaptive<F: Function<2>>(control: Control, f: F) {
+        Task::new(
+            DifferentialEvolution::new([-10.0..=10.0, -10.0..=10.0], 20, Strategy::Rand1Bin, 1e-12)
+                .with_control(control),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test]
+    fn test_tang_5() {
+        Task::new(
+            DifferentialEvolution::new([0; 5].map(|_| -5.0..=5.0), 80, Strategy::Rand1Bin, 1e-10)
+                .with_control(Control::Jde {
+                    tau_f: 0.1,
+                    tau_cr: 0.1,
+                }),
+            Tang,
+        )
+        .solve_space_check()
+        .with_eps_y(1e-4)
+        .check();
+    }
+}
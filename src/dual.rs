@@ -0,0 +1,438 @@
+use crate::functions::{GenericFunction, Point};
+use nalgebra::{SMatrix, SVector};
+use num::Zero;
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+/// Scalar the benchmark functions can be evaluated over.
+pub trait Real:
+    Copy
+    + Debug
+    + PartialEq
+    + Zero
+    + AddAssign
+    + Neg<Output = Self>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Add<f64, Output = Self>
+    + Sub<f64, Output = Self>
+    + Mul<f64, Output = Self>
+    + Div<f64, Output = Self>
+    + 'static
+{
+    fn constant(x: f64) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn sqrt(self) -> Self;
+    fn abs(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+}
+
+impl Real for f64 {
+    fn constant(x: f64) -> Self {
+        x
+    }
+
+    fn powi(self, n: i32) -> Self {
+        f64::powi(self, n)
+    }
+
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+
+    fn exp(self) -> Self {
+        f64::exp(self)
+    }
+
+    fn ln(self) -> Self {
+        f64::ln(self)
+    }
+
+    fn sin(self) -> Self {
+        f64::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        f64::cos(self)
+    }
+}
+
+/// Hyper-dual number `re + e1 * e1 + e2 * e2 + e12 * e1e2` with `e1^2 = e2^2 = 0`.
+///
+/// Seeding `e1` and `e2` along two coordinates yields the mixed second derivative in `e12`. With
+/// `e2 = e12 = 0` it degrades to an ordinary dual number carrying the first derivative in `e1`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HyperDual {
+    pub re: f64,
+    pub e1: f64,
+    pub e2: f64,
+    pub e12: f64,
+}
+
+/// Dual number `re + eps * e` with `e^2 = 0`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Dual {
+    pub re: f64,
+    pub eps: f64,
+}
+
+impl HyperDual {
+    pub fn new(re: f64, e1: f64, e2: f64, e12: f64) -> Self {
+        Self { re, e1, e2, e12 }
+    }
+
+    /// Applies a scalar function given its value and first two derivatives at `re`.
+    fn chain(self, g: f64, g1: f64, g2: f64) -> Self {
+        Self {
+            re: g,
+            e1: g1 * self.e1,
+            e2: g1 * self.e2,
+            e12: g1 * self.e12 + g2 * self.e1 * self.e2,
+        }
+    }
+}
+
+impl Dual {
+    pub fn new(re: f64, eps: f64) -> Self {
+        Self { re, eps }
+    }
+
+    /// Same as [`HyperDual::chain`], the second derivative is not tracked.
+    fn chain(self, g: f64, g1: f64, _g2: f64) -> Self {
+        Self {
+            re: g,
+            eps: g1 * self.eps,
+        }
+    }
+}
+
+impl Add for HyperDual {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            self.re + rhs.re,
+            self.e1 + rhs.e1,
+            self.e2 + rhs.e2,
+            self.e12 + rhs.e12,
+        )
+    }
+}
+
+impl Sub for HyperDual {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for HyperDual {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.re * rhs.re,
+            self.re * rhs.e1 + self.e1 * rhs.re,
+            self.re * rhs.e2 + self.e2 * rhs.re,
+            self.re * rhs.e12 + self.e12 * rhs.re + self.e1 * rhs.e2 + self.e2 * rhs.e1,
+        )
+    }
+}
+
+impl Div for HyperDual {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        let a = rhs.re;
+        self * rhs.chain(1.0 / a, -1.0 / (a * a), 2.0 / (a * a * a))
+    }
+}
+
+impl Neg for HyperDual {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.e1, -self.e2, -self.e12)
+    }
+}
+
+impl Add for Dual {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.eps + rhs.eps)
+    }
+}
+
+impl Sub for Dual {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.eps - rhs.eps)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.re * rhs.re, self.re * rhs.eps + self.eps * rhs.re)
+    }
+}
+
+impl Div for Dual {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self::new(
+            self.re / rhs.re,
+            (self.eps * rhs.re - self.re * rhs.eps) / (rhs.re * rhs.re),
+        )
+    }
+}
+
+impl Neg for Dual {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.eps)
+    }
+}
+
+macro_rules! impl_real {
+    ($t:ty) => {
+        impl Add<f64> for $t {
+            type Output = Self;
+
+            fn add(self, rhs: f64) -> Self {
+                self + Self::constant(rhs)
+            }
+        }
+
+        impl Sub<f64> for $t {
+            type Output = Self;
+
+            fn sub(self, rhs: f64) -> Self {
+                self - Self::constant(rhs)
+            }
+        }
+
+        impl Mul<f64> for $t {
+            type Output = Self;
+
+            fn mul(self, rhs: f64) -> Self {
+                self * Self::constant(rhs)
+            }
+        }
+
+        impl Div<f64> for $t {
+            type Output = Self;
+
+            fn div(self, rhs: f64) -> Self {
+                self * Self::constant(1.0 / rhs)
+            }
+        }
+
+        impl AddAssign for $t {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl Zero for $t {
+            fn zero() -> Self {
+                Self::default()
+            }
+
+            fn is_zero(&self) -> bool {
+                *self == Self::default()
+            }
+        }
+
+        impl Real for $t {
+            fn constant(re: f64) -> Self {
+                Self {
+                    re,
+                    ..Self::default()
+                }
+            }
+
+            fn powi(self, n: i32) -> Self {
+                let a = self.re;
+                let g1 = n as f64 * a.powi(n - 1);
+                let g2 = (n * (n - 1)) as f64 * a.powi(n - 2);
+                self.chain(a.powi(n), g1, g2)
+            }
+
+            fn sqrt(self) -> Self {
+                let s = self.re.sqrt();
+                self.chain(s, 0.5 / s, -0.25 / (s * self.re))
+            }
+
+            fn abs(self) -> Self {
+                self.chain(self.re.abs(), self.re.signum(), 0.0)
+            }
+
+            fn exp(self) -> Self {
+                let e = self.re.exp();
+                self.chain(e, e, e)
+            }
+
+            fn ln(self) -> Self {
+                let a = self.re;
+                self.chain(a.ln(), 1.0 / a, -1.0 / (a * a))
+            }
+
+            fn sin(self) -> Self {
+                let (s, c) = self.re.sin_cos();
+                self.chain(s, c, -s)
+            }
+
+            fn cos(self) -> Self {
+                let (s, c) = self.re.sin_cos();
+                self.chain(c, -s, -c)
+            }
+        }
+    };
+}
+
+impl_real!(Dual);
+impl_real!(HyperDual);
+
+/// Exact gradient by forward mode differentiation, one pass per coordinate.
+pub fn gradient<const N: usize, F: GenericFunction<N>>(x: Point<N>) -> Point<N> {
+    Point::from_fn(|i, _| {
+        let seeded =
+            SVector::<Dual, N>::from_fn(|j, _| Dual::new(x[j], if i == j { 1.0 } else { 0.0 }));
+        F::eval(seeded).eps
+    })
+}
+
+/// Exact Hessian using hyper-dual numbers, one pass per element of the upper triangle.
+pub fn hessian<const N: usize, F: GenericFunction<N>>(x: Point<N>) -> SMatrix<f64, N, N> {
+    let mut hessian = SMatrix::zeros();
+    for i in 0..N {
+        for j in i..N {
+            let seeded = SVector::<HyperDual, N>::from_fn(|k, _| {
+                let e1 = if k == i { 1.0 } else { 0.0 };
+                let e2 = if k == j { 1.0 } else { 0.0 };
+                HyperDual::new(x[k], e1, e2, 0.0)
+            });
+            hessian[(i, j)] = F::eval(seeded).e12;
+            hessian[(j, i)] = hessian[(i, j)];
+        }
+    }
+    hessian
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::derivatives::{Derivatives, Difference};
+    use crate::dual::{Dual, HyperDual, Real, gradient, hessian};
+    use crate::functions::*;
+    use approx::assert_relative_eq;
+    use nalgebra::SMatrix;
+    use test_case::test_case;
+
+    #[test]
+    fn test_dual_arithmetic() {
+        // f(x) = x^3 / (1 + x) at x = 2: f' = (3x^2 (1 + x) - x^3) / (1 + x)^2
+        let x = Dual::new(2.0, 1.0);
+        let f = x.powi(3) / (x + 1.0);
+
+        assert_eq!(f.re, 8.0 / 3.0);
+        assert_relative_eq!(f.eps, (12.0 * 3.0 - 8.0) / 9.0, max_relative = 1e-15);
+    }
+
+    #[test]
+    fn test_hyper_dual_second_derivative() {
+        // f(x) = sin(x) * exp(x): f'' = 2 cos(x) exp(x)
+        let x = 0.7;
+        let f = HyperDual::new(x, 1.0, 1.0, 0.0).sin() * HyperDual::new(x, 1.0, 1.0, 0.0).exp();
+
+        assert_relative_eq!(f.e1, (x.sin() + x.cos()) * x.exp(), max_relative = 1e-15);
+        assert_relative_eq!(f.e12, 2.0 * x.cos() * x.exp(), max_relative = 1e-15);
+    }
+
+    #[test]
+    fn test_rosenbrok_exact() {
+        let x: Point<2> = [-1.2, 1.0].into();
+        let expected_gradient: Point<2> = [
+            -400.0 * x[0] * (x[1] - x[0].powi(2)) - 2.0 * (1.0 - x[0]),
+            200.0 * (x[1] - x[0].powi(2)),
+        ]
+        .into();
+        let expected_hessian = SMatrix::<f64, 2, 2>::new(
+            1200.0 * x[0].powi(2) - 400.0 * x[1] + 2.0,
+            -400.0 * x[0],
+            -400.0 * x[0],
+            200.0,
+        );
+
+        assert_relative_eq!(
+            gradient::<2, Rosenbrok>(x),
+            expected_gradient,
+            max_relative = 1e-15
+        );
+        assert_relative_eq!(
+            hessian::<2, Rosenbrok>(x),
+            expected_hessian,
+            max_relative = 1e-15
+        );
+    }
+
+    #[test_case(Tang, |x| x.map(|x| x.powi(4) - 16.0 * x.powi(2) + 5.0 * x).sum() / 2.0)]
+    #[test_case(Rastrigin, |x| {
+        20.0 + x.map(|x| x * x - 10.0 * (std::f64::consts::TAU * x).cos()).sum()
+    })]
+    #[test_case(Sphere, |x| x.map(|x| x * x).sum())]
+    #[test_case(Rosenbrok, |x| 100.0 * (x[1] - x[0].powi(2)).powi(2) + (1.0 - x[0]).powi(2))]
+    #[test_case(Bukin6, |x| {
+        100.0 * (x[1] - 0.01 * x[0].powi(2)).abs().sqrt() + 0.01 * (x[0] + 10.0).abs()
+    })]
+    #[test_case(Himmelblau, |x| {
+        (x[0] * x[0] + x[1] - 11.0).powi(2) + (x[0] + x[1] * x[1] - 7.0).powi(2)
+    })]
+    #[test_case(Booth, |x| (x[0] + 2.0 * x[1] - 7.0).powi(2) + (2.0 * x[0] + x[1] - 5.0).powi(2))]
+    fn test_against_finite_differences<F: GenericFunction<2>>(
+        _: F,
+        closed_form: fn(Point<2>) -> f64,
+    ) {
+        let x: Point<2> = [-1.3, 0.4].into();
+
+        assert_relative_eq!(F::eval(x), closed_form(x), max_relative = 1e-12);
+        assert_relative_eq!(
+            gradient::<2, F>(x),
+            Difference::Richardson.gradient(F::f, &x),
+            epsilon = 1e-6,
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(
+            hessian::<2, F>(x),
+            Difference::Richardson.hessian(F::f, &x),
+            epsilon = 1e-4,
+            max_relative = 1e-4
+        );
+    }
+
+    #[test]
+    fn test_automatic_derivatives() {
+        let x: Point<2> = [3.5, -1.5].into();
+        let derivatives = Derivatives::automatic::<Himmelblau>();
+        let mut objective = derivatives.of(Himmelblau::f);
+
+        assert_eq!(objective.gradient(x), gradient::<2, Himmelblau>(x));
+        assert_eq!(objective.hessian(x), hessian::<2, Himmelblau>(x));
+        assert_eq!(objective.evaluations, 0);
+    }
+}
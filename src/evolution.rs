@@ -0,0 +1,268 @@
+use crate::functions::Point;
+use crate::method::Optimizer;
+use crate::utils::normal;
+use ordered_float::OrderedFloat;
+use rand::{RngExt, rng};
+
+/// Step size rule of the random search methods.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum StepControl {
+    /// The method's own shrinking by a constant factor.
+    #[default]
+    Classic,
+    /// Rechenberg's one-fifth success rule, see [`one_fifth`].
+    OneFifth,
+}
+
+/// Grows the step after a success and shrinks it after a failure so that it is stationary at a
+/// success rate of one fifth.
+pub fn one_fifth(step: f64, success: bool) -> f64 {
+    if success {
+        step * (1.0f64 / 3.0).exp()
+    } else {
+        step * (-1.0f64 / 12.0).exp()
+    }
+}
+
+fn gaussian<const N: usize>() -> Point<N> {
+    let mut random = rng();
+    Point::from_fn(|_, _| normal(&mut random))
+}
+
+#[derive(Debug)]
+pub struct EvolutionInfo {
+    pub generations: usize,
+    pub evaluations: usize,
+    pub sigma: f64,
+}
+
+/// `(1+1)`-ES: a single parent replaced by its Gaussian mutation when that is not worse, with
+/// the one-fifth success rule. Stops when the step falls below `eps`.
+#[derive(Clone)]
+pub struct OnePlusOne<const N: usize> {
+    start: Point<N>,
+    sigma: f64,
+    eps: f64,
+    max_evaluations: usize,
+}
+
+impl<const N: usize> OnePlusOne<N> {
+    pub fn new(start: Point<N>, sigma: f64, eps: f64) -> Self {
+        Self {
+            start,
+            sigma,
+            eps,
+            max_evaluations: 100_000 * N,
+        }
+    }
+
+    pub fn with_max_evaluations(self, max_evaluations: usize) -> Self {
+        Self {
+            max_evaluations,
+            ..self
+        }
+    }
+}
+
+impl<const N: usize> Optimizer for OnePlusOne<N> {
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = EvolutionInfo;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let mut x = self.start;
+        let mut fx = f(x);
+        let mut sigma = self.sigma;
+        let mut evaluations = 1;
+
+        while sigma >= self.eps && evaluations < self.max_evaluations {
+            let y = x + sigma * gaussian();
+            let fy = f(y);
+            evaluations += 1;
+
+            let success = fy <= fx;
+            if success {
+                (x, fx) = (y, fy);
+            }
+            sigma = one_fifth(sigma, success);
+        }
+
+        let info = EvolutionInfo {
+            generations: evaluations - 1,
+            evaluations,
+            sigma,
+        };
+        (x, fx, info)
+    }
+}
+
+/// Survivors of a generation.
+#[derive(Debug, Clone, Copy)]
+pub enum Survival {
+    /// `(mu, lambda)`: the best `mu` offspring, parents are forgotten.
+    Comma,
+    /// `(mu + lambda)`: the best `mu` of parents and offspring together.
+    Plus,
+}
+
+/// `(mu/mu, lambda)` and `(mu + lambda)` evolution strategies with self-adaptive step sizes.
+///
+/// Every offspring mutates its base point with a step size drawn log-normally around the base
+/// one, so that step sizes are selected together with the points. Under comma selection the
+/// base is the centroid of the parents with their mean step size, under plus selection a random
+/// parent, as a centroid worse than all the survivors would stall it. Stops when the mean step
+/// size falls below `eps`.
+#[derive(Clone)]
+pub struct Evolution<const N: usize> {
+    start: Point<N>,
+    sigma: f64,
+    mu: usize,
+    lambda: usize,
+    survival: Survival,
+    eps: f64,
+    max_evaluations: usize,
+}
+
+impl<const N: usize> Evolution<N> {
+    pub fn new(
+        start: Point<N>,
+        sigma: f64,
+        mu: usize,
+        lambda: usize,
+        survival: Survival,
+        eps: f64,
+    ) -> Self {
+        assert!(mu > 0 && lambda > 0, "mu and lambda must be positive");
+        assert!(
+            matches!(survival, Survival::Plus) || lambda >= mu,
+            "comma selection needs at least mu offspring"
+        );
+        Self {
+            start,
+            sigma,
+            mu,
+            lambda,
+            survival,
+            eps,
+            max_evaluations: 100_000 * N,
+        }
+    }
+
+    pub fn with_max_evaluations(self, max_evaluations: usize) -> Self {
+        Self {
+            max_evaluations,
+            ..self
+        }
+    }
+}
+
+impl<const N: usize> Optimizer for Evolution<N> {
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = EvolutionInfo;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let tau = 1.0 / (2.0 * N as f64).sqrt();
+        let mut random = rng();
+
+        let mut parents = vec![(self.start, self.sigma, f(self.start))];
+        let mut evaluations = 1;
+        let mut best = parents[0];
+        let mut generations = 0;
+
+        let mean_sigma = |parents: &[(Point<N>, f64, f64)]| {
+            (parents.iter().map(|(_, s, _)| s.ln()).sum::<f64>() / parents.len() as f64).exp()
+        };
+
+        while mean_sigma(&parents) >= self.eps && evaluations + self.lambda <= self.max_evaluations
+        {
+            let centroid =
+                parents.iter().map(|(x, _, _)| x).sum::<Point<N>>() / parents.len() as f64;
+            let sigma = mean_sigma(&parents);
+
+            let offspring = (0..self.lambda).map(|_| {
+                let (base, sigma) = match self.survival {
+                    Survival::Comma => (centroid, sigma),
+                    Survival::Plus => {
+                        let (x, s, _) = parents[random.random_range(0..parents.len())];
+                        (x, s)
+                    }
+                };
+                let s = sigma * (tau * normal(&mut random)).exp();
+                let x = base + s * gaussian();
+                (x, s, f(x))
+            });
+            let mut population: Vec<_> = match self.survival {
+                Survival::Comma => offspring.collect(),
+                Survival::Plus => parents.iter().copied().chain(offspring).collect(),
+            };
+            evaluations += self.lambda;
+            generations += 1;
+
+            population.sort_by_key(|(_, _, fx)| OrderedFloat(*fx));
+            population.truncate(self.mu);
+            parents = population;
+
+            if parents[0].2 < best.2 {
+                best = parents[0];
+            }
+        }
+
+        let info = EvolutionInfo {
+            generations,
+            evaluations,
+            sigma: mean_sigma(&parents),
+        };
+        (best.0, best.2, info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::evolution::{Evolution, OnePlusOne, Survival};
+    use crate::functions::{Booth, Function, Himmelblau, Rosenbrok, Sphere};
+    use crate::task::Task;
+    use test_case::test_case;
+
+    #[test_case(Sphere)]
+    #[test_case(Booth)]
+    #[test_case(Himmelblau)]
+    #[test_case(Rosenbrok)]
+    fn test_one_plus_one<F: Function<2>>(f: F) {
+        Task::new(OnePlusOne::new([-1.2, 1.0].into(), 1.0, 1e-10), f)
+            .solve_space_check()
+            .check();
+    }
+
+    #[test_case(Survival::Comma, Sphere; "comma_sphere")]
+    #[test_case(Survival::Comma, Booth; "comma_booth")]
+    #[test_case(Survival::Comma, Himmelblau; "comma_himmelblau")]
+    #[test_case(Survival::Plus, Sphere; "plus_sphere")]
+    #[test_case(Survival::Plus, Himmelblau; "plus_himmelblau")]
+    fn test_self_adaptive<F: Function<2>>(survival: Survival, f: F) {
+        Task::new(
+            Evolution::new([-1.2, 1.0].into(), 1.0, 3, 12, survival, 1e-10),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test]
+    fn test_evaluation_budget() {
+        let (_, _, info) = Task::new(
+            Evolution::new([-1.2, 1.0].into(), 1.0, 3, 50, Survival::Comma, 1e-10)
+                .with_max_evaluations(120),
+            Sphere,
+        )
+        .solve_space();
+
+        assert_eq!(info.evaluations, 101);
+    }
+}
@@ -52,12 +52,132 @@ impl Optimizer for GoldenRatio {
 
 impl GoldenRatio {
     const TAU: f64 = 0.618033988749894;
+
+    pub fn with_range(self, range: RangeInclusive<f64>) -> Self {
+        Self { range, ..self }
+    }
+}
+
+#[derive(Clone)]
+enum Budget {
+    Length(f64),
+    Evaluations(usize),
+}
+
+/// Fibonacci search with the number of function evaluations fixed up front.
+#[derive(Clone)]
+pub struct Fibonacci {
+    range: RangeInclusive<f64>,
+    budget: Budget,
+    delta: f64,
+}
+
+impl Fibonacci {
+    /// Chooses the number of evaluations so that the final interval is not longer than `eps`.
+    pub fn new(range: RangeInclusive<f64>, eps: f64) -> Self {
+        Self {
+            range,
+            budget: Budget::Length(eps),
+            delta: eps / 4.0,
+        }
+    }
+
+    /// Spends exactly `n` evaluations, `delta` separates the two points of the final step.
+    pub fn with_evaluations(range: RangeInclusive<f64>, n: usize, delta: f64) -> Self {
+        assert!(n >= 3, "Fibonacci search needs at least 3 evaluations");
+
+        Self {
+            range,
+            budget: Budget::Evaluations(n),
+            delta,
+        }
+    }
+
+    pub fn with_range(self, range: RangeInclusive<f64>) -> Self {
+        Self { range, ..self }
+    }
+
+    fn evaluations(&self) -> usize {
+        match self.budget {
+            Budget::Evaluations(n) => n,
+            Budget::Length(eps) => {
+                let length = self.range.end() - self.range.start();
+                let (mut n, mut prev, mut current) = (1, 1.0, 1.0);
+
+                while current < 2.0 * length / eps || n < 3 {
+                    (prev, current) = (current, prev + current);
+                    n += 1;
+                }
+                n
+            }
+        }
+    }
+
+    fn numbers(n: usize) -> Vec<f64> {
+        let mut numbers = vec![1.0; n + 1];
+        for i in 2..=n {
+            numbers[i] = numbers[i - 1] + numbers[i - 2];
+        }
+        numbers
+    }
+}
+
+impl Optimizer for Fibonacci {
+    type X = f64;
+    type F = f64;
+    type Metadata = Steps;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let n = self.evaluations();
+        let fib = Self::numbers(n);
+        let mut a = *self.range.start();
+        let mut b = *self.range.end();
+
+        let mut x1 = a + fib[n - 2] / fib[n] * (b - a);
+        let mut x2 = a + fib[n - 1] / fib[n] * (b - a);
+        let mut f1 = f(x1);
+        let mut f2 = f(x2);
+
+        for k in 1..=n - 2 {
+            if f1 < f2 {
+                b = x2;
+                x2 = x1;
+                f2 = f1;
+                x1 = if k == n - 2 {
+                    x2 - self.delta
+                } else {
+                    a + fib[n - k - 2] / fib[n - k] * (b - a)
+                };
+                f1 = f(x1);
+            } else {
+                a = x1;
+                x1 = x2;
+                f1 = f2;
+                x2 = if k == n - 2 {
+                    x1 + self.delta
+                } else {
+                    a + fib[n - k - 1] / fib[n - k] * (b - a)
+                };
+                f2 = f(x2);
+            }
+        }
+
+        if f1 < f2 {
+            (x1, f1, Steps(n - 2))
+        } else {
+            (x2, f2, Steps(n - 2))
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::fibonacci::GoldenRatio;
+    use crate::fibonacci::{Fibonacci, GoldenRatio};
     use crate::functions::{Sphere, Tang};
+    use crate::method::Optimizer;
     use crate::task::Task;
 
     #[test]
@@ -73,4 +193,30 @@ mod tests {
             .solve_check()
             .check();
     }
+
+    #[test]
+    fn test_fibonacci_tang() {
+        Task::new(Fibonacci::new(-5.0..=0.0, 1e-6), Tang)
+            .solve_check()
+            .check();
+    }
+
+    #[test]
+    fn test_fibonacci_sphere() {
+        Task::new(Fibonacci::new(-5.0..=0.0, 1e-6), Sphere)
+            .solve_check()
+            .check();
+    }
+
+    #[test]
+    fn test_fibonacci_evaluations() {
+        let mut calls = 0;
+        let (x, _, _) = Fibonacci::with_evaluations(-5.0..=5.0, 20, 1e-5).optimize(|x| {
+            calls += 1;
+            (x - 1.0).powi(2)
+        });
+
+        assert_eq!(calls, 20);
+        approx::assert_relative_eq!(x, 1.0, epsilon = 1e-3);
+    }
 }
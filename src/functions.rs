@@ -1,3 +1,4 @@
+use crate::dual::Real;
 use nalgebra::SVector;
 use num::traits::FloatConst;
 use std::convert::Into;
@@ -10,6 +11,11 @@ pub trait Function<const N: usize> {
     fn f(x: Point<N>) -> f64;
 }
 
+/// Function that can be evaluated over any [`Real`] scalar, e.g. dual numbers for exact derivatives.
+pub trait GenericFunction<const N: usize>: Function<N> {
+    fn eval<T: Real>(x: SVector<T, N>) -> T;
+}
+
 pub type Point<const N: usize> = SVector<f64, N>;
 
 pub struct Tang<const N: usize>;
@@ -24,7 +30,14 @@ impl<const N: usize> Function<N> for Tang<N> {
 
     #[inline]
     fn f(x: Point<N>) -> f64 {
-        x.map(|x| x.powi(4) - 16.0 * x.powi(2) + 5.0 * x).sum() / 2.0
+        Self::eval(x)
+    }
+}
+
+impl<const N: usize> GenericFunction<N> for Tang<N> {
+    #[inline]
+    fn eval<T: Real>(x: SVector<T, N>) -> T {
+        x.map(|x| x.powi(4) - x.powi(2) * 16.0 + x * 5.0).sum() / 2.0
     }
 }
 
@@ -39,7 +52,14 @@ impl<const N: usize> Function<N> for Rastrigin<N> {
 
     #[inline]
     fn f(x: Point<N>) -> f64 {
-        10.0 * N as f64 + x.map(|x| x * x - 10.0 * (f64::TAU() * x).cos()).sum()
+        Self::eval(x)
+    }
+}
+
+impl<const N: usize> GenericFunction<N> for Rastrigin<N> {
+    #[inline]
+    fn eval<T: Real>(x: SVector<T, N>) -> T {
+        x.map(|x| x * x - (x * f64::TAU()).cos() * 10.0).sum() + 10.0 * N as f64
     }
 }
 
@@ -54,6 +74,13 @@ impl<const N: usize> Function<N> for Sphere<N> {
 
     #[inline]
     fn f(x: Point<N>) -> f64 {
+        Self::eval(x)
+    }
+}
+
+impl<const N: usize> GenericFunction<N> for Sphere<N> {
+    #[inline]
+    fn eval<T: Real>(x: SVector<T, N>) -> T {
         x.map(|x| x * x).sum()
     }
 }
@@ -68,11 +95,18 @@ impl Function<2> for Rosenbrok {
     }
 
     #[inline]
-    fn f(xs: Point<2>) -> f64 {
+    fn f(x: Point<2>) -> f64 {
+        Self::eval(x)
+    }
+}
+
+impl GenericFunction<2> for Rosenbrok {
+    #[inline]
+    fn eval<T: Real>(xs: SVector<T, 2>) -> T {
         let x = xs[0];
         let y = xs[1];
 
-        100.0 * (y - x.powi(2)).powi(2) + (1.0 - x).powi(2)
+        (y - x.powi(2)).powi(2) * 100.0 + (-x + 1.0).powi(2)
     }
 }
 
@@ -86,11 +120,18 @@ impl Function<2> for Bukin6 {
     }
 
     #[inline]
-    fn f(xs: Point<2>) -> f64 {
+    fn f(x: Point<2>) -> f64 {
+        Self::eval(x)
+    }
+}
+
+impl GenericFunction<2> for Bukin6 {
+    #[inline]
+    fn eval<T: Real>(xs: SVector<T, 2>) -> T {
         let x = xs[0];
         let y = xs[1];
 
-        100.0 * (y - 0.01 * x.powi(2)).abs().sqrt() + 0.01 * (x + 10.0).abs()
+        (y - x.powi(2) * 0.01).abs().sqrt() * 100.0 + (x + 10.0).abs() * 0.01
     }
 }
 
@@ -109,7 +150,14 @@ impl Function<2> for Himmelblau {
     }
 
     #[inline]
-    fn f(xs: Point<2>) -> f64 {
+    fn f(x: Point<2>) -> f64 {
+        Self::eval(x)
+    }
+}
+
+impl GenericFunction<2> for Himmelblau {
+    #[inline]
+    fn eval<T: Real>(xs: SVector<T, 2>) -> T {
         let x = xs[0];
         let y = xs[1];
 
@@ -127,10 +175,17 @@ impl Function<2> for Booth {
     }
 
     #[inline]
-    fn f(xs: Point<2>) -> f64 {
+    fn f(x: Point<2>) -> f64 {
+        Self::eval(x)
+    }
+}
+
+impl GenericFunction<2> for Booth {
+    #[inline]
+    fn eval<T: Real>(xs: SVector<T, 2>) -> T {
         let x = xs[0];
         let y = xs[1];
 
-        (x + 2.0 * y - 7.0).powi(2) + (2.0 * x + y - 5.0).powi(2)
+        (x + y * 2.0 - 7.0).powi(2) + (x * 2.0 + y - 5.0).powi(2)
     }
 }
@@ -0,0 +1,357 @@
+use crate::functions::Point;
+use crate::method::Optimizer;
+use crate::utils::normal;
+use nalgebra::SVector;
+use ordered_float::OrderedFloat;
+use rand::{RngExt, rng};
+use std::ops::RangeInclusive;
+
+/// Choice of a parent from the population.
+#[derive(Debug, Clone, Copy)]
+pub enum Selection {
+    /// Best of `size` individuals drawn uniformly.
+    Tournament { size: usize },
+    /// Probability proportional to the distance of the value from the worst one.
+    Roulette,
+    /// Linear ranking, probability proportional to the position from the worst.
+    Rank,
+}
+
+impl Selection {
+    /// Index of a parent, `values` are sorted in ascending order.
+    fn select(&self, values: &[f64]) -> usize {
+        let mut random = rng();
+        let n = values.len();
+        let weighted = |weight: &dyn Fn(usize) -> f64, random: &mut rand::rngs::ThreadRng| {
+            let total: f64 = (0..n).map(weight).sum();
+            let mut target = random.random::<f64>() * total;
+            for i in 0..n {
+                target -= weight(i);
+                if target <= 0.0 {
+                    return i;
+                }
+            }
+            n - 1
+        };
+
+        match *self {
+            Selection::Tournament { size } => {
+                (0..size).map(|_| random.random_range(0..n)).min().unwrap()
+            }
+            Selection::Roulette => {
+                let worst = values[n - 1];
+                let scale = (worst - values[0]).max(f64::MIN_POSITIVE);
+                // Shifted so that the worst individual keeps a small chance
+                weighted(
+                    &|i| (worst - values[i]) / scale + 1.0 / n as f64,
+                    &mut random,
+                )
+            }
+            Selection::Rank => weighted(&|i| (n - i) as f64, &mut random),
+        }
+    }
+}
+
+/// Recombination of two parents into two children.
+#[derive(Debug, Clone, Copy)]
+pub enum Crossover {
+    /// Simulated binary crossover, children are closer to the parents for larger `eta`.
+    Sbx { eta: f64 },
+    /// Blend crossover, genes uniform in the parents' interval extended by `alpha` of its width.
+    Blx { alpha: f64 },
+    /// Convex combinations with a random weight.
+    Arithmetic,
+}
+
+impl Crossover {
+    fn children<const N: usize>(&self, a: &Point<N>, b: &Point<N>) -> (Point<N>, Point<N>) {
+        let mut random = rng();
+        let (mut c, mut d) = (*a, *b);
+
+        match *self {
+            Crossover::Sbx { eta } => {
+                for j in 0..N {
+                    if random.random::<f64>() < 0.5 {
+                        continue;
+                    }
+                    let u: f64 = random.random();
+                    let beta = if u <= 0.5 {
+                        (2.0 * u).powf(1.0 / (eta + 1.0))
+                    } else {
+                        (1.0 / (2.0 * (1.0 - u))).powf(1.0 / (eta + 1.0))
+                    };
+                    c[j] = 0.5 * ((1.0 + beta) * a[j] + (1.0 - beta) * b[j]);
+                    d[j] = 0.5 * ((1.0 - beta) * a[j] + (1.0 + beta) * b[j]);
+                }
+            }
+            Crossover::Blx { alpha } => {
+                for j in 0..N {
+                    let (lo, hi) = (a[j].min(b[j]), a[j].max(b[j]));
+                    let extent = alpha * (hi - lo);
+                    let range = (lo - extent)..=(hi + extent);
+                    c[j] = random.random_range(range.clone());
+                    d[j] = random.random_range(range);
+                }
+            }
+            Crossover::Arithmetic => {
+                let lambda: f64 = random.random();
+                c = lambda * a + (1.0 - lambda) * b;
+                d = (1.0 - lambda) * a + lambda * b;
+            }
+        }
+        (c, d)
+    }
+}
+
+/// Perturbation of each gene with the given `probability`, scaled by the width of its range.
+#[derive(Debug, Clone, Copy)]
+pub enum Mutation {
+    /// Deb's polynomial mutation, smaller steps for larger `eta`.
+    Polynomial {
+        eta: f64,
+        probability: f64,
+    },
+    Gaussian {
+        sigma: f64,
+        probability: f64,
+    },
+}
+
+impl Mutation {
+    fn mutate<const N: usize>(&self, x: &mut Point<N>, width: &Point<N>) {
+        let mut random = rng();
+        let probability = match *self {
+            Mutation::Polynomial { probability, .. } | Mutation::Gaussian { probability, .. } => {
+                probability
+            }
+        };
+
+        for j in 0..N {
+            if random.random::<f64>() >= probability {
+                continue;
+            }
+            let delta = match *self {
+                Mutation::Polynomial { eta, .. } => {
+                    let u: f64 = random.random();
+                    if u < 0.5 {
+                        (2.0 * u).powf(1.0 / (eta + 1.0)) - 1.0
+                    } else {
+                        1.0 - (2.0 * (1.0 - u)).powf(1.0 / (eta + 1.0))
+                    }
+                }
+                Mutation::Gaussian { sigma, .. } => sigma * normal(&mut random),
+            };
+            x[j] += delta * width[j];
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Statistics {
+    pub best: f64,
+    pub mean: f64,
+    pub deviation: f64,
+}
+
+#[derive(Debug)]
+pub struct GeneticInfo {
+    pub evaluations: usize,
+    /// Values of the population in each generation, starting with the initial one.
+    pub statistics: Vec<Statistics>,
+}
+
+/// Real-coded genetic algorithm over a box, runs for a fixed number of generations.
+#[derive(Clone)]
+pub struct Genetic<const N: usize> {
+    bounds: SVector<RangeInclusive<f64>, N>,
+    population: usize,
+    generations: usize,
+    selection: Selection,
+    crossover: Crossover,
+    mutation: Mutation,
+    crossover_rate: f64,
+    elitism: usize,
+}
+
+impl<const N: usize> Genetic<N> {
+    /// Tournament of two, SBX with `eta = 15`, polynomial mutation of one gene on average with
+    /// `eta = 20` and the single best individual kept.
+    pub fn new(bounds: [RangeInclusive<f64>; N], population: usize, generations: usize) -> Self {
+        assert!(population > 0, "The population must not be empty");
+
+        Self {
+            bounds: bounds.into(),
+            population,
+            generations,
+            selection: Selection::Tournament { size: 2 },
+            crossover: Crossover::Sbx { eta: 15.0 },
+            mutation: Mutation::Polynomial {
+                eta: 20.0,
+                probability: 1.0 / N as f64,
+            },
+            crossover_rate: 0.9,
+            elitism: 1,
+        }
+    }
+
+    pub fn with_selection(self, selection: Selection) -> Self {
+        if let Selection::Tournament { size } = selection {
+            assert!(size > 0, "A tournament needs at least one individual");
+        }
+        Self { selection, ..self }
+    }
+
+    pub fn with_crossover(self, crossover: Crossover, rate: f64) -> Self {
+        Self {
+            crossover,
+            crossover_rate: rate,
+            ..self
+        }
+    }
+
+    pub fn with_mutation(self, mutation: Mutation) -> Self {
+        Self { mutation, ..self }
+    }
+
+    /// Number of best individuals copied unchanged into the next generation.
+    pub fn with_elitism(self, elitism: usize) -> Self {
+        assert!(
+            elitism <= self.population,
+            "Cannot keep more elites than the population"
+        );
+        Self { elitism, ..self }
+    }
+
+    fn clamp(&self, x: Point<N>) -> Point<N> {
+        x.zip_map(&self.bounds, |x, range| {
+            x.clamp(*range.start(), *range.end())
+        })
+    }
+}
+
+impl<const N: usize> Optimizer for Genetic<N> {
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = GeneticInfo;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let mut random = rng();
+        let map_to = |x, y: RangeInclusive<f64>| (y.end() - y.start()) * x + y.start();
+        let width = self.bounds.map(|range| range.end() - range.start());
+
+        let evaluate = |xs: Vec<Point<N>>, f: &mut dyn FnMut(Point<N>) -> f64| {
+            let mut individuals: Vec<_> = xs.into_iter().map(|x| (x, f(x))).collect();
+            individuals.sort_by_key(|(_, fx)| OrderedFloat(*fx));
+            individuals
+        };
+        let statistics = |individuals: &[(Point<N>, f64)]| {
+            let n = individuals.len() as f64;
+            let mean = individuals.iter().map(|(_, fx)| fx).sum::<f64>() / n;
+            let variance = individuals
+                .iter()
+                .map(|(_, fx)| (fx - mean).powi(2))
+                .sum::<f64>()
+                / n;
+            Statistics {
+                best: individuals[0].1,
+                mean,
+                deviation: variance.sqrt(),
+            }
+        };
+
+        let initial = (0..self.population)
+            .map(|_| Point::<N>::new_random().zip_map(&self.bounds, map_to))
+            .collect();
+        let mut individuals = evaluate(initial, &mut f);
+        let mut evaluations = self.population;
+        let mut history = vec![statistics(&individuals)];
+
+        for _ in 0..self.generations {
+            let values: Vec<f64> = individuals.iter().map(|(_, fx)| *fx).collect();
+            let mut offspring = vec![];
+
+            while offspring.len() < self.population - self.elitism {
+                let a = individuals[self.selection.select(&values)].0;
+                let b = individuals[self.selection.select(&values)].0;
+                let (mut c, mut d) = if random.random::<f64>() < self.crossover_rate {
+                    self.crossover.children(&a, &b)
+                } else {
+                    (a, b)
+                };
+                self.mutation.mutate(&mut c, &width);
+                self.mutation.mutate(&mut d, &width);
+                offspring.push(self.clamp(c));
+                offspring.push(self.clamp(d));
+            }
+            offspring.truncate(self.population - self.elitism);
+            evaluations += offspring.len();
+
+            let mut next = evaluate(offspring, &mut f);
+            next.extend_from_slice(&individuals[..self.elitism]);
+            next.sort_by_key(|(_, fx)| OrderedFloat(*fx));
+            individuals = next;
+            history.push(statistics(&individuals));
+        }
+
+        let (x, fx) = individuals[0];
+        let info = GeneticInfo {
+            evaluations,
+            statistics: history,
+        };
+        (x, fx, info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::functions::{Booth, Function, Himmelblau, Rastrigin, Sphere};
+    use crate::genetic::{Crossover, Genetic, Mutation, Selection};
+    use crate::task::Task;
+    use test_case::test_case;
+
+    #[test_case(Selection::Tournament { size: 3 }, Crossover::Sbx { eta: 15.0 }; "tournament_sbx")]
+    #[test_case(Selection::Roulette, Crossover::Blx { alpha: 0.5 }; "roulette_blx")]
+    #[test_case(Selection::Rank, Crossover::Arithmetic; "rank_arithmetic")]
+    fn test_rastrigin(selection: Selection, crossover: Crossover) {
+        Task::new(
+            Genetic::new([-5.12..=5.12, -5.12..=5.12], 100, 400)
+                .with_selection(selection)
+                .with_crossover(crossover, 0.9)
+                .with_elitism(2),
+            Rastrigin,
+        )
+        .solve_space_check()
+        .with_eps_x(1e-2)
+        .with_eps_y(5e-2)
+        .check();
+    }
+
+    #[test_case(Booth, Mutation::Polynomial { eta: 20.0, probability: 0.5 }; "booth_polynomial")]
+    #[test_case(Himmelblau, Mutation::Gaussian { sigma: 0.01, probability: 0.5 }; "himmelblau_gaussian")]
+    fn test_mutation<F: Function<2>>(f: F, mutation: Mutation) {
+        Task::new(
+            Genetic::new([-10.0..=10.0, -10.0..=10.0], 60, 300).with_mutation(mutation),
+            f,
+        )
+        .solve_space_check()
+        .with_eps_x(1e-2)
+        .with_eps_y(1e-3)
+        .check();
+    }
+
+    #[test]
+    fn test_statistics() {
+        let (_, f, info) =
+            Task::new(Genetic::new([-10.0..=10.0, -10.0..=10.0], 50, 30), Sphere).solve_space();
+
+        assert_eq!(info.statistics.len(), 31);
+        assert_eq!(info.evaluations, 50 + 30 * 49);
+        assert_eq!(info.statistics.last().unwrap().best, f);
+        // The elite is kept, so the best value never gets worse
+        assert!(info.statistics.windows(2).all(|w| w[1].best <= w[0].best));
+        assert!(info.statistics.iter().all(|s| s.best <= s.mean));
+    }
+}
@@ -0,0 +1,191 @@
+use crate::derivatives::Derivatives;
+use crate::functions::Point;
+use crate::line_search::{LineSearch, Ray};
+use crate::method::{OneDimensionalMethod, Optimizer, Steps};
+
+/// Step size rule along the antigradient.
+#[derive(Clone)]
+pub enum Step {
+    Constant(f64),
+    /// `initial / (1 + decay * k)` on the `k`-th iteration.
+    Diminishing {
+        initial: f64,
+        decay: f64,
+    },
+    /// Step minimizing the function along the antigradient.
+    Exact(OneDimensionalMethod),
+    /// Step satisfying the conditions of an inexact line search.
+    Inexact(LineSearch),
+}
+
+#[derive(Clone)]
+pub struct GradientDescent<const N: usize> {
+    start: Point<N>,
+    derivatives: Derivatives<N>,
+    step: Step,
+    eps_gradient: f64,
+    eps_x: f64,
+    eps_y: f64,
+    max_steps: usize,
+}
+
+impl<const N: usize> GradientDescent<N> {
+    pub fn new(
+        start: Point<N>,
+        derivatives: Derivatives<N>,
+        step: Step,
+        eps_gradient: f64,
+        eps_x: f64,
+        eps_y: f64,
+    ) -> Self {
+        Self {
+            start,
+            derivatives,
+            step,
+            eps_gradient,
+            eps_x,
+            eps_y,
+            max_steps: 1_000_000,
+        }
+    }
+
+    pub fn with_max_steps(self, max_steps: usize) -> Self {
+        Self { max_steps, ..self }
+    }
+}
+
+impl<const N: usize> Optimizer for GradientDescent<N> {
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = Steps;
+
+    fn optimize(&self, f: impl FnMut(Self::X) -> Self::F) -> (Self::X, Self::F, Self::Metadata) {
+        let mut objective = self.derivatives.of(f);
+        let mut x = self.start;
+        let mut fx = objective.value(x);
+        let mut r = 0;
+
+        while r < self.max_steps {
+            let gradient = objective.gradient(x);
+            if gradient.norm() < self.eps_gradient {
+                break;
+            }
+
+            let h = match &self.step {
+                Step::Constant(h) => *h,
+                Step::Diminishing { initial, decay } => initial / (1.0 + decay * r as f64),
+                Step::Exact(optimizer) => {
+                    let (h, _, _) = optimizer.optimize(|h| objective.value(x - h * gradient));
+                    h
+                }
+                Step::Inexact(search) => {
+                    let mut ray = Ray::new(&mut objective, x, -gradient);
+                    let dphi0 = -gradient.norm_squared();
+                    let (h, _, _) = search.search(&mut ray, fx, dphi0);
+                    h
+                }
+            };
+
+            let next = x - h * gradient;
+            let f_next = objective.value(next);
+            let (dx, dy) = ((next - x).norm(), (f_next - fx).abs());
+            (x, fx) = (next, f_next);
+            r += 1;
+
+            if dx < self.eps_x || dy < self.eps_y {
+                break;
+            }
+        }
+
+        (x, fx, Steps(r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::brent::Brent;
+    use crate::derivatives::{Derivatives, Difference};
+    use crate::fibonacci::GoldenRatio;
+    use crate::functions::{Booth, Function, GenericFunction, Himmelblau, Rosenbrok, Sphere};
+    use crate::gradient_descent::{GradientDescent, Step};
+    use crate::method::OneDimensionalMethod;
+    use crate::task::Task;
+    use test_case::test_case;
+
+    #[test_case(Booth; "booth")]
+    #[test_case(Sphere; "sphere")]
+    #[test_case(Himmelblau; "himmelblau")]
+    fn test_constant<F: GenericFunction<2> + 'static>(f: F) {
+        Task::new(
+            GradientDescent::new(
+                [-1.0, 1.0].into(),
+                Derivatives::automatic::<F>(),
+                Step::Constant(0.01),
+                1e-9,
+                1e-15,
+                1e-15,
+            ),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test]
+    fn test_diminishing_sphere() {
+        Task::new(
+            GradientDescent::new(
+                [-4.0, 3.0].into(),
+                Derivatives::Numeric(Difference::Central),
+                Step::Diminishing {
+                    initial: 0.4,
+                    decay: 0.01,
+                },
+                1e-9,
+                1e-15,
+                1e-15,
+            ),
+            Sphere,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test_case(Booth; "booth")]
+    #[test_case(Rosenbrok; "rosenbrok")]
+    fn test_exact<F: Function<2>>(f: F) {
+        let method = OneDimensionalMethod::from(Brent::new(0.0..=1.0, 1e-12));
+
+        Task::new(
+            GradientDescent::new(
+                [-1.2, 1.0].into(),
+                Derivatives::Numeric(Difference::Richardson),
+                Step::Exact(method.auto_bracket(0.0, 1e-3, 60)),
+                1e-8,
+                0.0,
+                0.0,
+            ),
+            f,
+        )
+        .solve_space_check()
+        .with_eps_x(1e-4)
+        .check();
+    }
+
+    #[test]
+    fn test_exact_golden_ratio_booth() {
+        Task::new(
+            GradientDescent::new(
+                [-4.0, -4.0].into(),
+                Derivatives::Numeric(Difference::Central),
+                Step::Exact(GoldenRatio::new(0.0..=1.0, 1e-9).into()),
+                1e-6,
+                1e-12,
+                1e-14,
+            ),
+            Booth,
+        )
+        .solve_space_check()
+        .check();
+    }
+}
@@ -0,0 +1,101 @@
+use crate::functions::Point;
+use crate::method::{Optimizer, Steps};
+use derive_more::Constructor;
+
+#[derive(Constructor, Clone)]
+pub struct HookeJeeves<const N: usize> {
+    start: Point<N>,
+    step: f64,
+    alpha: f64,
+    eps: f64,
+}
+
+impl<const N: usize> HookeJeeves<N> {
+    fn explore(
+        f: &mut impl FnMut(Point<N>) -> f64,
+        mut x: Point<N>,
+        mut fx: f64,
+        step: f64,
+    ) -> (Point<N>, f64) {
+        for i in 0..N {
+            for delta in [step, -step] {
+                let mut y = x;
+                y[i] += delta;
+                let fy = f(y);
+
+                if fy < fx {
+                    (x, fx) = (y, fy);
+                    break;
+                }
+            }
+        }
+        (x, fx)
+    }
+}
+
+impl<const N: usize> Optimizer for HookeJeeves<N> {
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = Steps;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let mut r = 0;
+        let mut step = self.step;
+        let mut x = self.start;
+        let mut fx = f(x);
+
+        while step >= self.eps {
+            let (mut next, mut f_next) = Self::explore(&mut f, x, fx, step);
+
+            if f_next < fx {
+                loop {
+                    let pattern = next + (next - x);
+                    (x, fx) = (next, f_next);
+                    let f_pattern = f(pattern);
+                    let (y, fy) = Self::explore(&mut f, pattern, f_pattern, step);
+
+                    if fy < fx {
+                        (next, f_next) = (y, fy);
+                    } else {
+                        break;
+                    }
+                }
+            } else {
+                step *= self.alpha;
+            }
+            r += 1;
+        }
+
+        (x, fx, Steps(r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::functions::{Booth, Function, Himmelblau, Rosenbrok, Sphere};
+    use crate::hooke_jeeves::HookeJeeves;
+    use crate::method::GlobalMultiMethod;
+    use crate::task::Task;
+    use test_case::test_case;
+
+    #[test_case(Booth; "booth")]
+    #[test_case(Sphere; "sphere")]
+    #[test_case(Himmelblau; "himmelblau")]
+    #[test_case(Rosenbrok; "rosenbrok")]
+    fn test<F: Function<2>>(f: F) {
+        Task::new(HookeJeeves::new([-1.2, 1.0].into(), 0.5, 0.5, 1e-10), f)
+            .solve_space_check()
+            .check();
+    }
+
+    #[test]
+    fn test_hooke_jeeves_global_multi_method() {
+        let method: GlobalMultiMethod<2> =
+            HookeJeeves::new([4.0, -4.0].into(), 1.0, 0.5, 1e-10).into();
+
+        Task::new(method, Booth).solve_space_check().check();
+    }
+}
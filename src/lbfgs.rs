@@ -0,0 +1,233 @@
+use crate::derivatives::Difference;
+use crate::line_search::{Line, LineSearch};
+use crate::method::{Optimizer, Steps};
+use nalgebra::DVector;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+type Point = DVector<f64>;
+type GradientFn = Rc<dyn Fn(&Point) -> Point>;
+
+/// Limited memory BFGS keeping the last `m` correction pairs instead of a dense matrix.
+#[derive(Clone)]
+pub struct LBfgs {
+    start: Point,
+    m: usize,
+    search: LineSearch,
+    difference: Difference,
+    gradient: Option<GradientFn>,
+    eps: f64,
+    max_steps: usize,
+}
+
+impl LBfgs {
+    /// Uses the strong Wolfe line search with `c1 = 1e-4`, `c2 = 0.9` and central differences.
+    pub fn new(start: Point, m: usize, eps: f64) -> Self {
+        assert!(m > 0, "L-BFGS needs room for at least one correction pair");
+
+        Self {
+            start,
+            m,
+            search: LineSearch::strong_wolfe(1e-4, 0.9),
+            difference: Difference::Central,
+            gradient: None,
+            eps,
+            max_steps: 100_000,
+        }
+    }
+
+    pub fn with_search(self, search: LineSearch) -> Self {
+        Self { search, ..self }
+    }
+
+    pub fn with_difference(self, difference: Difference) -> Self {
+        Self { difference, ..self }
+    }
+
+    pub fn with_gradient(self, gradient: impl Fn(&Point) -> Point + 'static) -> Self {
+        Self {
+            gradient: Some(Rc::new(gradient)),
+            ..self
+        }
+    }
+
+    pub fn with_max_steps(self, max_steps: usize) -> Self {
+        Self { max_steps, ..self }
+    }
+
+    fn gradient(&self, f: &mut impl FnMut(Point) -> f64, x: &Point) -> Point {
+        match &self.gradient {
+            Some(gradient) => gradient(x),
+            None => self.difference.gradient(f, x),
+        }
+    }
+
+    /// Two-loop recursion computing `H * q` from the stored pairs `(s, y, 1 / s^T y)`.
+    fn apply(history: &VecDeque<(Point, Point, f64)>, mut q: Point) -> Point {
+        let mut alphas = Vec::with_capacity(history.len());
+        for (s, y, rho) in history.iter().rev() {
+            let alpha = rho * s.dot(&q);
+            q.axpy(-alpha, y, 1.0);
+            alphas.push(alpha);
+        }
+
+        if let Some((s, y, _)) = history.back() {
+            q *= s.dot(y) / y.norm_squared();
+        }
+
+        for ((s, y, rho), alpha) in history.iter().zip(alphas.into_iter().rev()) {
+            let beta = rho * y.dot(&q);
+            q.axpy(alpha - beta, s, 1.0);
+        }
+        q
+    }
+}
+
+struct Ray<'a, F> {
+    optimizer: &'a LBfgs,
+    f: &'a mut F,
+    x: &'a Point,
+    p: &'a Point,
+}
+
+impl<F: FnMut(Point) -> f64> Line for Ray<'_, F> {
+    fn value(&mut self, alpha: f64) -> f64 {
+        (self.f)(self.x + alpha * self.p)
+    }
+
+    fn slope(&mut self, alpha: f64) -> f64 {
+        let x = self.x + alpha * self.p;
+        self.optimizer.gradient(self.f, &x).dot(self.p)
+    }
+}
+
+impl Optimizer for LBfgs {
+    type X = Point;
+    type F = f64;
+    type Metadata = Steps;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let mut history = VecDeque::with_capacity(self.m);
+        let mut x = self.start.clone();
+        let mut fx = f(x.clone());
+        let mut gradient = self.gradient(&mut f, &x);
+        let mut r = 0;
+
+        while r < self.max_steps && gradient.norm() >= self.eps {
+            let mut direction = -Self::apply(&history, gradient.clone());
+            let mut slope = gradient.dot(&direction);
+            if slope >= 0.0 {
+                history.clear();
+                direction = -gradient.clone();
+                slope = -gradient.norm_squared();
+            }
+
+            let initial = if history.is_empty() {
+                1.0 / gradient.norm().max(1.0)
+            } else {
+                1.0
+            };
+            let mut ray = Ray {
+                optimizer: self,
+                f: &mut f,
+                x: &x,
+                p: &direction,
+            };
+            let (alpha, f_next, _) = self.search.search_from(&mut ray, fx, slope, initial);
+            if alpha == 0.0 {
+                break;
+            }
+
+            let s = alpha * direction;
+            x += &s;
+            fx = f_next;
+            let next = self.gradient(&mut f, &x);
+            let y = &next - &gradient;
+            gradient = next;
+            r += 1;
+
+            let sy = s.dot(&y);
+            if sy > f64::EPSILON * s.norm() * y.norm() {
+                if history.len() == self.m {
+                    history.pop_front();
+                }
+                history.push_back((s, y, 1.0 / sy));
+            }
+        }
+
+        (x, fx, Steps(r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::derivatives::Difference;
+    use crate::functions::{Booth, Function, Himmelblau, Rosenbrok, Sphere};
+    use crate::lbfgs::LBfgs;
+    use crate::line_search::LineSearch;
+    use crate::method::{Optimizer, Steps};
+    use crate::task::Task;
+    use approx::assert_relative_eq;
+    use nalgebra::DVector;
+    use test_case::test_case;
+
+    #[test_case(Rosenbrok; "rosenbrok")]
+    #[test_case(Booth; "booth")]
+    #[test_case(Sphere; "sphere")]
+    #[test_case(Himmelblau; "himmelblau")]
+    fn test<F: Function<2>>(f: F) {
+        Task::new(
+            LBfgs::new(DVector::from_vec(vec![-1.2, 1.0]), 5, 1e-8)
+                .with_difference(Difference::Richardson),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test]
+    fn test_tang_armijo() {
+        Task::new(
+            LBfgs::new(DVector::from_element(3, -2.0), 3, 1e-8)
+                .with_search(LineSearch::armijo(1e-4, 0.5)),
+            crate::functions::Tang,
+        )
+        .solve_space_check::<3>()
+        .with_eps_y(1e-4)
+        .check();
+    }
+
+    #[test]
+    fn test_extended_rosenbrok() {
+        let n = 1000;
+        let f = |x: DVector<f64>| {
+            (0..n / 2)
+                .map(|i| {
+                    let (a, b) = (x[2 * i], x[2 * i + 1]);
+                    100.0 * (b - a * a).powi(2) + (1.0 - a).powi(2)
+                })
+                .sum::<f64>()
+        };
+        let gradient = move |x: &DVector<f64>| {
+            let mut g = DVector::zeros(n);
+            for i in 0..n / 2 {
+                let (a, b) = (x[2 * i], x[2 * i + 1]);
+                g[2 * i] = -400.0 * a * (b - a * a) - 2.0 * (1.0 - a);
+                g[2 * i + 1] = 200.0 * (b - a * a);
+            }
+            g
+        };
+        let start = DVector::from_fn(n, |i, _| if i % 2 == 0 { -1.2 } else { 1.0 });
+
+        let (x, y, Steps(r)) = LBfgs::new(start, 10, 1e-8)
+            .with_gradient(gradient)
+            .optimize(f);
+
+        assert!(r < 1000, "Took {r} iterations");
+        assert_relative_eq!(x, DVector::from_element(n, 1.0), epsilon = 1e-6);
+        assert!(y < 1e-12);
+    }
+}
@@ -0,0 +1,434 @@
+use crate::derivatives::Difference;
+use crate::dual::Dual;
+use crate::functions::Point;
+use crate::line_search::{Line, LineSearch};
+use crate::method::Optimizer;
+use nalgebra::allocator::Allocator;
+use nalgebra::{Const, DefaultAllocator, Dim, OMatrix, OVector, SMatrix, SVector};
+use std::rc::Rc;
+
+/// Residual vector of static or dynamic length `M`.
+pub type ResidualFn<const N: usize, M> = Rc<dyn Fn(Point<N>) -> OVector<f64, M>>;
+pub type JacobianFn<const N: usize, M> = Rc<dyn Fn(Point<N>) -> OMatrix<f64, M, Const<N>>>;
+
+#[derive(Clone)]
+pub enum Jacobian<const N: usize, M: Dim>
+where
+    DefaultAllocator: Allocator<M> + Allocator<M, Const<N>>,
+{
+    Numeric(Difference),
+    Exact(JacobianFn<N, M>),
+}
+
+impl<const N: usize, M: Dim + 'static> Jacobian<N, M>
+where
+    DefaultAllocator: Allocator<M> + Allocator<M, Const<N>>,
+{
+    pub fn exact(jacobian: impl Fn(Point<N>) -> OMatrix<f64, M, Const<N>> + 'static) -> Self {
+        Jacobian::Exact(Rc::new(jacobian))
+    }
+
+    /// Forward mode automatic differentiation of the residuals `r` written over dual numbers,
+    /// one pass per parameter.
+    pub fn automatic(r: impl Fn(SVector<Dual, N>) -> OVector<Dual, M> + 'static) -> Self
+    where
+        DefaultAllocator: Allocator<M>,
+    {
+        Jacobian::exact(move |x: Point<N>| {
+            let columns: Vec<_> = (0..N)
+                .map(|j| {
+                    let seeded = SVector::<Dual, N>::from_fn(|i, _| {
+                        Dual::new(x[i], if i == j { 1.0 } else { 0.0 })
+                    });
+                    r(seeded).map(|r| r.eps)
+                })
+                .collect();
+
+            let mut jacobian = OMatrix::zeros_generic(columns[0].shape_generic().0, Const::<N>);
+            for (j, column) in columns.iter().enumerate() {
+                jacobian.set_column(j, column);
+            }
+            jacobian
+        })
+    }
+}
+
+/// Nonlinear least-squares problem `min |r(x)|^2`.
+#[derive(Clone)]
+pub struct LeastSquares<const N: usize, M: Dim>
+where
+    DefaultAllocator: Allocator<M> + Allocator<M, Const<N>>,
+{
+    residuals: ResidualFn<N, M>,
+    jacobian: Jacobian<N, M>,
+}
+
+impl<const N: usize, M: Dim> LeastSquares<N, M>
+where
+    DefaultAllocator: Allocator<M> + Allocator<M, Const<N>>,
+{
+    pub fn new(
+        residuals: impl Fn(Point<N>) -> OVector<f64, M> + 'static,
+        jacobian: Jacobian<N, M>,
+    ) -> Self {
+        Self {
+            residuals: Rc::new(residuals),
+            jacobian,
+        }
+    }
+
+    pub fn residuals(&self, x: Point<N>) -> OVector<f64, M> {
+        (self.residuals)(x)
+    }
+
+    /// Sum of squared residuals, the scalar objective the solvers minimize.
+    pub fn cost(&self, x: Point<N>) -> f64 {
+        self.residuals(x).norm_squared()
+    }
+
+    pub fn jacobian(&self, x: Point<N>) -> OMatrix<f64, M, Const<N>> {
+        match &self.jacobian {
+            Jacobian::Numeric(difference) => {
+                difference.jacobian::<Const<N>, M>(&*self.residuals, &x)
+            }
+            Jacobian::Exact(jacobian) => jacobian(x),
+        }
+    }
+
+    fn fit(&self, steps: usize, x: Point<N>) -> Fit<N> {
+        let jacobian = self.jacobian(x);
+        Fit {
+            steps,
+            residual_norm: self.residuals(x).norm(),
+            covariance: jacobian.tr_mul(&jacobian).try_inverse(),
+        }
+    }
+}
+
+/// Result of a least-squares solver.
+#[derive(Debug, Clone)]
+pub struct Fit<const N: usize> {
+    pub steps: usize,
+    pub residual_norm: f64,
+    /// `(J^T J)^-1` at the solution, scale it by `residual_norm^2 / (M - N)` to estimate the
+    /// covariance of the parameters under independent equal-variance noise. `None` if `J` is
+    /// rank deficient.
+    pub covariance: Option<SMatrix<f64, N, N>>,
+}
+
+/// Merit function along the Gauss–Newton direction with its slope from the Jacobian.
+struct Merit<'a, const N: usize, M: Dim, F>
+where
+    DefaultAllocator: Allocator<M> + Allocator<M, Const<N>>,
+{
+    f: F,
+    problem: &'a LeastSquares<N, M>,
+    x: Point<N>,
+    p: Point<N>,
+}
+
+impl<const N: usize, M: Dim, F: FnMut(Point<N>) -> f64> Line for Merit<'_, N, M, F>
+where
+    DefaultAllocator: Allocator<M> + Allocator<M, Const<N>>,
+{
+    fn value(&mut self, alpha: f64) -> f64 {
+        (self.f)(self.x + alpha * self.p)
+    }
+
+    fn slope(&mut self, alpha: f64) -> f64 {
+        let y = self.x + alpha * self.p;
+        let r = self.problem.residuals(y);
+        2.0 * self.problem.jacobian(y).tr_mul(&r).dot(&self.p)
+    }
+}
+
+/// Gauss–Newton method, damped by a line search on the sum of squares.
+///
+/// The function passed to [`Optimizer::optimize`] must be the sum of squared residuals of the
+/// problem, [`GaussNewton::fit`] supplies it.
+#[derive(Clone)]
+pub struct GaussNewton<const N: usize, M: Dim>
+where
+    DefaultAllocator: Allocator<M> + Allocator<M, Const<N>>,
+{
+    start: Point<N>,
+    problem: LeastSquares<N, M>,
+    search: LineSearch,
+    eps: f64,
+    max_steps: usize,
+}
+
+impl<const N: usize, M: Dim> GaussNewton<N, M>
+where
+    DefaultAllocator: Allocator<M> + Allocator<M, Const<N>>,
+{
+    pub fn new(start: Point<N>, problem: LeastSquares<N, M>, eps: f64) -> Self {
+        Self {
+            start,
+            problem,
+            search: LineSearch::armijo(1e-4, 0.5),
+            eps,
+            max_steps: 1000,
+        }
+    }
+
+    pub fn with_search(self, search: LineSearch) -> Self {
+        Self { search, ..self }
+    }
+
+    pub fn with_max_steps(self, max_steps: usize) -> Self {
+        Self { max_steps, ..self }
+    }
+
+    pub fn fit(&self) -> (Point<N>, Fit<N>) {
+        let (x, _, fit) = self.optimize(|x| self.problem.cost(x));
+        (x, fit)
+    }
+}
+
+impl<const N: usize, M: Dim> Optimizer for GaussNewton<N, M>
+where
+    DefaultAllocator: Allocator<M> + Allocator<M, Const<N>>,
+{
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = Fit<N>;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let mut x = self.start;
+        let mut fx = f(x);
+        let mut r = 0;
+
+        while r < self.max_steps {
+            let residuals = self.problem.residuals(x);
+            let jacobian = self.problem.jacobian(x);
+            let gradient = jacobian.tr_mul(&residuals);
+            if gradient.amax() < self.eps {
+                break;
+            }
+
+            let Some(p) = jacobian
+                .tr_mul(&jacobian)
+                .cholesky()
+                .map(|c| c.solve(&-gradient))
+            else {
+                break;
+            };
+
+            let mut merit = Merit {
+                f: &mut f,
+                problem: &self.problem,
+                x,
+                p,
+            };
+            let (alpha, phi, _) = self.search.search(&mut merit, fx, 2.0 * gradient.dot(&p));
+            r += 1;
+
+            if phi.is_nan() || phi >= fx {
+                break;
+            }
+            x += alpha * p;
+            fx = phi;
+            if alpha * p.norm() < self.eps * (x.norm() + self.eps) {
+                break;
+            }
+        }
+
+        (x, fx, self.problem.fit(r, x))
+    }
+}
+
+/// Levenberg–Marquardt method: Gauss–Newton steps regularized by a damping `mu`, updated from
+/// the ratio of actual to predicted reduction.
+///
+/// The function passed to [`Optimizer::optimize`] must be the sum of squared residuals of the
+/// problem, [`LevenbergMarquardt::fit`] supplies it.
+#[derive(Clone)]
+pub struct LevenbergMarquardt<const N: usize, M: Dim>
+where
+    DefaultAllocator: Allocator<M> + Allocator<M, Const<N>>,
+{
+    start: Point<N>,
+    problem: LeastSquares<N, M>,
+    /// Initial damping relative to the largest diagonal entry of `J^T J`.
+    tau: f64,
+    eps: f64,
+    max_steps: usize,
+}
+
+impl<const N: usize, M: Dim> LevenbergMarquardt<N, M>
+where
+    DefaultAllocator: Allocator<M> + Allocator<M, Const<N>>,
+{
+    pub fn new(start: Point<N>, problem: LeastSquares<N, M>, eps: f64) -> Self {
+        Self {
+            start,
+            problem,
+            tau: 1e-3,
+            eps,
+            max_steps: 1000,
+        }
+    }
+
+    pub fn with_tau(self, tau: f64) -> Self {
+        Self { tau, ..self }
+    }
+
+    pub fn with_max_steps(self, max_steps: usize) -> Self {
+        Self { max_steps, ..self }
+    }
+
+    pub fn fit(&self) -> (Point<N>, Fit<N>) {
+        let (x, _, fit) = self.optimize(|x| self.problem.cost(x));
+        (x, fit)
+    }
+}
+
+impl<const N: usize, M: Dim> Optimizer for LevenbergMarquardt<N, M>
+where
+    DefaultAllocator: Allocator<M> + Allocator<M, Const<N>>,
+{
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = Fit<N>;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let mut x = self.start;
+        let mut fx = f(x);
+        let normal = |x| {
+            let residuals = self.problem.residuals(x);
+            let jacobian = self.problem.jacobian(x);
+            (jacobian.tr_mul(&jacobian), jacobian.tr_mul(&residuals))
+        };
+
+        let (mut a, mut gradient) = normal(x);
+        let mut mu = self.tau * a.diagonal().max();
+        let mut nu = 2.0;
+        let mut r = 0;
+
+        while r < self.max_steps && gradient.amax() >= self.eps {
+            r += 1;
+            let damped = a + SMatrix::<f64, N, N>::identity() * mu;
+            let Some(h) = damped.cholesky().map(|c| c.solve(&-gradient)) else {
+                mu *= nu;
+                nu *= 2.0;
+                continue;
+            };
+            if h.norm() < self.eps * (x.norm() + self.eps) {
+                break;
+            }
+
+            // Reduction of the linearized sum of squares
+            let predicted = h.dot(&(mu * h - gradient));
+            let f_next = f(x + h);
+            let rho = (fx - f_next) / predicted;
+
+            if rho > 0.0 {
+                x += h;
+                fx = f_next;
+                (a, gradient) = normal(x);
+                mu *= (1.0 - (2.0 * rho - 1.0).powi(3)).max(1.0 / 3.0);
+                nu = 2.0;
+            } else {
+                mu *= nu;
+                nu *= 2.0;
+            }
+        }
+
+        (x, fx, self.problem.fit(r, x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::derivatives::Difference;
+    use crate::dual::Real;
+    use crate::functions::{Booth, Function, Himmelblau, Point, Rosenbrok};
+    use crate::least_squares::{GaussNewton, Jacobian, LeastSquares, LevenbergMarquardt};
+    use crate::task::Task;
+    use approx::assert_relative_eq;
+    use nalgebra::{Const, DVector, SVector};
+    use test_case::test_case;
+
+    fn rosenbrok<T: Real>(x: SVector<T, 2>) -> SVector<T, 2> {
+        SVector::<T, 2>::new((x[1] - x[0].powi(2)) * 10.0, -x[0] + 1.0)
+    }
+
+    fn himmelblau<T: Real>(x: SVector<T, 2>) -> SVector<T, 2> {
+        SVector::<T, 2>::new(x[0] * x[0] + x[1] - 11.0, x[0] + x[1] * x[1] - 7.0)
+    }
+
+    fn booth<T: Real>(x: SVector<T, 2>) -> SVector<T, 2> {
+        SVector::<T, 2>::new(x[0] + x[1] * 2.0 - 7.0, x[0] * 2.0 + x[1] - 5.0)
+    }
+
+    type Residuals = fn(Point<2>) -> SVector<f64, 2>;
+
+    fn problem(residuals: Residuals, jacobian: Jacobian<2, Const<2>>) -> LeastSquares<2, Const<2>> {
+        LeastSquares::new(residuals, jacobian)
+    }
+
+    #[test_case(rosenbrok, Jacobian::automatic(rosenbrok), Rosenbrok; "rosenbrok_residuals")]
+    #[test_case(himmelblau, Jacobian::automatic(himmelblau), Himmelblau; "himmelblau_residuals")]
+    #[test_case(booth, Jacobian::Numeric(Difference::Central), Booth; "booth_residuals")]
+    fn test_gauss_newton<F: Function<2>>(
+        residuals: Residuals,
+        jacobian: Jacobian<2, Const<2>>,
+        f: F,
+    ) {
+        Task::new(
+            GaussNewton::new([-1.2, 1.0].into(), problem(residuals, jacobian), 1e-10),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test_case(rosenbrok, Jacobian::automatic(rosenbrok), Rosenbrok; "rosenbrok_residuals")]
+    #[test_case(himmelblau, Jacobian::Numeric(Difference::Central), Himmelblau; "himmelblau_residuals")]
+    #[test_case(booth, Jacobian::automatic(booth), Booth; "booth_residuals")]
+    fn test_levenberg_marquardt<F: Function<2>>(
+        residuals: Residuals,
+        jacobian: Jacobian<2, Const<2>>,
+        f: F,
+    ) {
+        Task::new(
+            LevenbergMarquardt::new([-1.2, 1.0].into(), problem(residuals, jacobian), 1e-10),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test]
+    fn test_curve_fit() {
+        // y = a exp(b t) sampled with a small deterministic perturbation
+        let t = DVector::from_fn(20, |i, _| i as f64 / 10.0);
+        let y = t.map(|t| 2.5 * (-1.3 * t).exp() + 1e-3 * (7.0 * t).sin());
+
+        let (tr, yr) = (t.clone(), y.clone());
+        let residuals = move |x: Point<2>| {
+            DVector::from_fn(tr.len(), |i, _| x[0] * (x[1] * tr[i]).exp() - yr[i])
+        };
+        let jacobian = Jacobian::automatic(move |x| {
+            DVector::from_fn(t.len(), |i, _| x[0] * (x[1] * t[i]).exp() - y[i])
+        });
+
+        let problem = LeastSquares::new(residuals, jacobian);
+        let (x, fit) = LevenbergMarquardt::new([1.0, 0.0].into(), problem.clone(), 1e-12).fit();
+        assert_relative_eq!(x, Point::<2>::new(2.5, -1.3), epsilon = 1e-2);
+        assert!(fit.residual_norm < 1e-2);
+        let covariance = fit.covariance.unwrap();
+        assert!(covariance[(0, 0)] > 0.0 && covariance[(1, 1)] > 0.0);
+
+        let (x_gn, fit_gn) = GaussNewton::new([1.0, 0.0].into(), problem, 1e-12).fit();
+        assert_relative_eq!(x_gn, x, epsilon = 1e-8);
+        assert_relative_eq!(fit_gn.residual_norm, fit.residual_norm, epsilon = 1e-10);
+    }
+}
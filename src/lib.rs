@@ -1,20 +1,43 @@
 #![allow(dead_code)]
 #![cfg_attr(all(feature = "nightly", test), feature(test))]
 
+mod annealing;
 mod approx_model;
 mod backward;
 mod binary;
+mod bracket;
+mod brent;
+mod cma_es;
 mod compound;
 mod conjugate_directions;
+mod conjugate_gradient;
+mod derivatives;
+mod differential_evolution;
+mod dual;
 mod enumerate;
+mod evolution;
 mod fibonacci;
 mod functions;
+mod genetic;
+mod gradient_descent;
+mod hooke_jeeves;
 mod iterative_conditional;
+mod lbfgs;
+mod least_squares;
+mod line_search;
+mod lipschitz;
 mod method;
+mod multi_start;
+mod nelder_mead;
+mod newton;
+mod particle_swarm;
+mod quasi_newton;
 mod repeating;
 mod restriction;
+mod rotating;
 mod task;
 // mod uniform;
+mod trust_region;
 mod utils;
 mod zeidel;
 
@@ -0,0 +1,518 @@
+use crate::derivatives::{Difference, Objective};
+use crate::functions::Point;
+use crate::method::{OneDimensionalMethod, Optimizer, Steps};
+use derive_more::From;
+
+/// Restriction `phi(alpha) = f(x + alpha * p)` of an objective to a ray.
+pub trait Line {
+    fn value(&mut self, alpha: f64) -> f64;
+    fn slope(&mut self, alpha: f64) -> f64;
+}
+
+pub struct Ray<'a, 'b, const N: usize, F> {
+    objective: &'a mut Objective<'b, N, F>,
+    x: Point<N>,
+    p: Point<N>,
+}
+
+impl<'a, 'b, const N: usize, F> Ray<'a, 'b, N, F> {
+    pub fn new(objective: &'a mut Objective<'b, N, F>, x: Point<N>, p: Point<N>) -> Self {
+        Self { objective, x, p }
+    }
+}
+
+impl<const N: usize, F: FnMut(Point<N>) -> f64> Line for Ray<'_, '_, N, F> {
+    fn value(&mut self, alpha: f64) -> f64 {
+        self.objective.value(self.x + alpha * self.p)
+    }
+
+    fn slope(&mut self, alpha: f64) -> f64 {
+        self.objective
+            .gradient(self.x + alpha * self.p)
+            .dot(&self.p)
+    }
+}
+
+/// Line known only by its values, the slope is found by central differences.
+struct Numeric<F>(F);
+
+impl<F: FnMut(f64) -> f64> Line for Numeric<F> {
+    fn value(&mut self, alpha: f64) -> f64 {
+        (self.0)(alpha)
+    }
+
+    fn slope(&mut self, alpha: f64) -> f64 {
+        Difference::Central.derivative(&mut self.0, alpha)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Condition {
+    /// Sufficient decrease, the step is shrunk by `rho` until it holds.
+    Armijo { c1: f64, rho: f64 },
+    /// Sufficient decrease and curvature `|phi'(alpha)| <= c2 |phi'(0)|`.
+    StrongWolfe { c1: f64, c2: f64 },
+    /// `phi(0) + (1 - c) alpha phi'(0) <= phi(alpha) <= phi(0) + c alpha phi'(0)`, `0 < c < 1/2`.
+    Goldstein { c: f64 },
+}
+
+/// Inexact line search along a descent direction.
+#[derive(Debug, Clone)]
+pub struct LineSearch {
+    condition: Condition,
+    initial: f64,
+    max_steps: usize,
+}
+
+impl LineSearch {
+    pub fn new(condition: Condition) -> Self {
+        Self {
+            condition,
+            initial: 1.0,
+            max_steps: 100,
+        }
+    }
+
+    pub fn armijo(c1: f64, rho: f64) -> Self {
+        Self::new(Condition::Armijo { c1, rho })
+    }
+
+    pub fn strong_wolfe(c1: f64, c2: f64) -> Self {
+        Self::new(Condition::StrongWolfe { c1, c2 })
+    }
+
+    pub fn goldstein(c: f64) -> Self {
+        Self::new(Condition::Goldstein { c })
+    }
+
+    pub fn with_initial_step(self, initial: f64) -> Self {
+        Self { initial, ..self }
+    }
+
+    pub fn with_max_steps(self, max_steps: usize) -> Self {
+        Self { max_steps, ..self }
+    }
+
+    /// Finds a step satisfying the condition given `phi(0)` and `phi'(0) < 0`.
+    pub fn search(&self, line: &mut impl Line, phi0: f64, dphi0: f64) -> (f64, f64, Steps) {
+        self.search_from(line, phi0, dphi0, self.initial)
+    }
+
+    pub fn search_from(
+        &self,
+        line: &mut impl Line,
+        phi0: f64,
+        dphi0: f64,
+        initial: f64,
+    ) -> (f64, f64, Steps) {
+        match self.condition {
+            Condition::Armijo { c1, rho } => {
+                self.armijo_search(line, phi0, dphi0, initial, c1, rho)
+            }
+            Condition::StrongWolfe { c1, c2 } => {
+                self.wolfe_search(line, phi0, dphi0, initial, c1, c2)
+            }
+            Condition::Goldstein { c } => self.goldstein_search(line, phi0, dphi0, initial, c),
+        }
+    }
+
+    fn armijo_search(
+        &self,
+        line: &mut impl Line,
+        phi0: f64,
+        dphi0: f64,
+        initial: f64,
+        c1: f64,
+        rho: f64,
+    ) -> (f64, f64, Steps) {
+        let mut alpha = initial;
+        let mut phi = line.value(alpha);
+        let mut r = 1;
+
+        while phi > phi0 + c1 * alpha * dphi0 && r < self.max_steps {
+            alpha *= rho;
+            phi = line.value(alpha);
+            r += 1;
+        }
+
+        (alpha, phi, Steps(r))
+    }
+
+    fn goldstein_search(
+        &self,
+        line: &mut impl Line,
+        phi0: f64,
+        dphi0: f64,
+        initial: f64,
+        c: f64,
+    ) -> (f64, f64, Steps) {
+        let (mut lo, mut hi) = (0.0, f64::INFINITY);
+        let mut alpha = initial;
+        let mut phi = line.value(alpha);
+        let mut r = 1;
+
+        while r < self.max_steps {
+            if phi > phi0 + c * alpha * dphi0 {
+                hi = alpha;
+            } else if phi < phi0 + (1.0 - c) * alpha * dphi0 {
+                lo = alpha;
+            } else {
+                break;
+            }
+
+            alpha = if hi.is_finite() {
+                (lo + hi) / 2.0
+            } else {
+                2.0 * alpha
+            };
+            phi = line.value(alpha);
+            r += 1;
+        }
+
+        (alpha, phi, Steps(r))
+    }
+
+    /// Moré–Thuente search: safeguarded cubic and quadratic steps inside an interval of
+    /// uncertainty that is extrapolated until it brackets a point satisfying the conditions.
+    fn wolfe_search(
+        &self,
+        line: &mut impl Line,
+        phi0: f64,
+        dphi0: f64,
+        initial: f64,
+        c1: f64,
+        c2: f64,
+    ) -> (f64, f64, Steps) {
+        const XTRAPL: f64 = 1.1;
+        const XTRAPU: f64 = 4.0;
+        const XTOL: f64 = 1e-14;
+
+        let gtest = c1 * dphi0;
+        let mut interval = Interval {
+            x: (0.0, phi0, dphi0),
+            y: (0.0, phi0, dphi0),
+            bracketed: false,
+        };
+        let (mut min, mut max) = (0.0, initial + XTRAPU * initial);
+        let mut width = f64::INFINITY;
+        let mut previous_width = f64::INFINITY;
+        // Until a step with sufficient decrease and a nonnegative slope is found, the steps are
+        // chosen on `psi(alpha) = phi(alpha) - phi0 - c1 alpha phi'(0)` instead of `phi`.
+        let mut modified = true;
+        let mut alpha = initial;
+        let mut r = 0;
+
+        while r < self.max_steps {
+            if interval.bracketed && (alpha <= min || alpha >= max || max - min <= XTOL * max) {
+                break;
+            }
+
+            let phi = line.value(alpha);
+            let dphi = line.slope(alpha);
+            let ftest = phi0 + alpha * gtest;
+            r += 1;
+
+            if phi <= ftest && dphi.abs() <= -c2 * dphi0 {
+                return (alpha, phi, Steps(r));
+            }
+            if modified && phi <= ftest && dphi >= c1.min(c2) * dphi0 {
+                modified = false;
+            }
+
+            if modified && phi <= interval.x.1 && phi > ftest {
+                let shift = |(t, f, g): (f64, f64, f64)| (t, f - t * gtest, g - gtest);
+                let mut shifted = Interval {
+                    x: shift(interval.x),
+                    y: shift(interval.y),
+                    bracketed: interval.bracketed,
+                };
+                alpha = shifted.step(shift((alpha, phi, dphi)), min, max);
+
+                let unshift = |(t, f, g): (f64, f64, f64)| (t, f + t * gtest, g + gtest);
+                interval = Interval {
+                    x: unshift(shifted.x),
+                    y: unshift(shifted.y),
+                    bracketed: shifted.bracketed,
+                };
+            } else {
+                alpha = interval.step((alpha, phi, dphi), min, max);
+            }
+
+            let (x, y) = (interval.x.0, interval.y.0);
+            if interval.bracketed {
+                if (y - x).abs() >= 0.66 * previous_width {
+                    alpha = x + (y - x) / 2.0;
+                }
+                previous_width = width;
+                width = (y - x).abs();
+                (min, max) = (x.min(y), x.max(y));
+            } else {
+                (min, max) = (alpha + XTRAPL * (alpha - x), alpha + XTRAPU * (alpha - x));
+            }
+            alpha = alpha.max(0.0);
+        }
+
+        let (alpha, phi, _) = interval.x;
+        (alpha, phi, Steps(r))
+    }
+}
+
+/// Interval of uncertainty of the Moré–Thuente search. `x` is the step with the lowest value so
+/// far, `y` the other endpoint, each stored with its value and slope.
+struct Interval {
+    x: (f64, f64, f64),
+    y: (f64, f64, f64),
+    bracketed: bool,
+}
+
+impl Interval {
+    /// Computes the next trial step from the trial `(t, ft, gt)` and updates the interval, the
+    /// result is kept within `[min, max]` while the minimizer is not bracketed.
+    fn step(&mut self, (t, ft, gt): (f64, f64, f64), min: f64, max: f64) -> f64 {
+        let (x, fx, gx) = self.x;
+        let (y, fy, gy) = self.y;
+        let sign = gt * gx.signum();
+
+        let cubic = |(a, fa, ga): (f64, f64, f64), (b, fb, gb): (f64, f64, f64)| {
+            let theta = 3.0 * (fa - fb) / (b - a) + ga + gb;
+            let s = theta.abs().max(ga.abs()).max(gb.abs());
+            let gamma = s * ((theta / s).powi(2) - (ga / s) * (gb / s)).max(0.0).sqrt();
+            let gamma = if b < a { -gamma } else { gamma };
+            let p = (gamma - ga) + theta;
+            let q = ((gamma - ga) + gamma) + gb;
+            (a + p / q * (b - a), gamma, p / q)
+        };
+        let secant = t + gt / (gt - gx) * (x - t);
+
+        let next = if ft > fx {
+            // Higher value: the minimizer is bracketed, take the cubic step unless it is much
+            // farther from `x` than the quadratic one.
+            let (c, _, _) = cubic((x, fx, gx), (t, ft, gt));
+            let q = x + gx / ((fx - ft) / (t - x) + gx) / 2.0 * (t - x);
+            self.bracketed = true;
+            if (c - x).abs() < (q - x).abs() {
+                c
+            } else {
+                c + (q - c) / 2.0
+            }
+        } else if sign < 0.0 {
+            // Slopes of opposite sign: bracketed, take the step farther from `t`.
+            let (c, _, _) = cubic((t, ft, gt), (x, fx, gx));
+            self.bracketed = true;
+            if (c - t).abs() > (secant - t).abs() {
+                c
+            } else {
+                secant
+            }
+        } else if gt.abs() < gx.abs() {
+            // Slope decreases in magnitude: the cubic is used only if it has a minimizer beyond `t`.
+            let (_, gamma, ratio) = cubic((t, ft, gt), (x, fx, gx));
+            let c = if ratio < 0.0 && gamma != 0.0 {
+                t + ratio * (x - t)
+            } else if t > x {
+                max
+            } else {
+                min
+            };
+
+            if self.bracketed {
+                let next = if (c - t).abs() < (secant - t).abs() {
+                    c
+                } else {
+                    secant
+                };
+                let limit = t + 0.66 * (y - t);
+                if t > x {
+                    next.min(limit)
+                } else {
+                    next.max(limit)
+                }
+            } else {
+                let next = if (c - t).abs() > (secant - t).abs() {
+                    c
+                } else {
+                    secant
+                };
+                next.max(min).min(max)
+            }
+        } else if self.bracketed {
+            cubic((t, ft, gt), (y, fy, gy)).0
+        } else if t > x {
+            max
+        } else {
+            min
+        };
+
+        if ft > fx {
+            self.y = (t, ft, gt);
+        } else {
+            if sign < 0.0 {
+                self.y = self.x;
+            }
+            self.x = (t, ft, gt);
+        }
+
+        next
+    }
+}
+
+/// Searches along whichever direction decreases the function, the slope at zero is found by
+/// finite differences.
+impl Optimizer for LineSearch {
+    type X = f64;
+    type F = f64;
+    type Metadata = Steps;
+
+    fn optimize(&self, f: impl FnMut(Self::X) -> Self::F) -> (Self::X, Self::F, Self::Metadata) {
+        let mut line = Numeric(f);
+        let phi0 = line.value(0.0);
+        let dphi0 = line.slope(0.0);
+
+        if dphi0 == 0.0 {
+            return (0.0, phi0, Steps(0));
+        }
+
+        let sign = -dphi0.signum();
+        let mut reversed = Numeric(|alpha: f64| line.value(sign * alpha));
+        let (alpha, phi, steps) = self.search(&mut reversed, phi0, -dphi0.abs());
+
+        (sign * alpha, phi, steps)
+    }
+}
+
+/// Step length selection for direction based methods.
+#[derive(Clone, From)]
+pub enum Search {
+    /// Minimizes the function along the direction.
+    Exact(OneDimensionalMethod),
+    Inexact(LineSearch),
+}
+
+impl Search {
+    /// Step along the descent direction `p` from `x`, returns it with the function value there.
+    pub fn step<const N: usize, F: FnMut(Point<N>) -> f64>(
+        &self,
+        objective: &mut Objective<'_, N, F>,
+        x: Point<N>,
+        p: Point<N>,
+        (phi0, dphi0): (f64, f64),
+        initial: f64,
+    ) -> (f64, f64) {
+        match self {
+            Search::Exact(optimizer) => {
+                let (alpha, phi, _) = optimizer.optimize(|alpha| objective.value(x + alpha * p));
+                (alpha, phi)
+            }
+            Search::Inexact(search) => {
+                let mut ray = Ray::new(objective, x, p);
+                let (alpha, phi, _) = search.search_from(&mut ray, phi0, dphi0, initial);
+                (alpha, phi)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::derivatives::{Derivatives, Difference};
+    use crate::functions::{Booth, Function, Himmelblau, Point, Rosenbrok};
+    use crate::gradient_descent::{GradientDescent, Step};
+    use crate::line_search::{Line, LineSearch, Ray};
+    use crate::task::Task;
+    use crate::zeidel::GaussZeidel;
+    use test_case::test_case;
+
+    const C1: f64 = 1e-4;
+
+    fn start() -> (Point<2>, Point<2>) {
+        let x: Point<2> = [-1.2, 1.0].into();
+        let derivatives = Derivatives::automatic::<Rosenbrok>();
+        let gradient = derivatives.of(Rosenbrok::f).gradient(x);
+        (x, -gradient)
+    }
+
+    #[test]
+    fn test_armijo() {
+        let (x, p) = start();
+        let derivatives = Derivatives::automatic::<Rosenbrok>();
+        let mut objective = derivatives.of(Rosenbrok::f);
+        let mut ray = Ray::new(&mut objective, x, p);
+        let (phi0, dphi0) = (ray.value(0.0), ray.slope(0.0));
+
+        let (alpha, phi, _) = LineSearch::armijo(C1, 0.5).search(&mut ray, phi0, dphi0);
+
+        assert!(alpha > 0.0);
+        assert!(phi <= phi0 + C1 * alpha * dphi0);
+    }
+
+    #[test_case(0.9)]
+    #[test_case(0.1)]
+    fn test_strong_wolfe(c2: f64) {
+        let (x, p) = start();
+        let derivatives = Derivatives::automatic::<Rosenbrok>();
+        let mut objective = derivatives.of(Rosenbrok::f);
+        let mut ray = Ray::new(&mut objective, x, p);
+        let (phi0, dphi0) = (ray.value(0.0), ray.slope(0.0));
+
+        let (alpha, phi, _) = LineSearch::strong_wolfe(C1, c2)
+            .with_initial_step(1e-4)
+            .search(&mut ray, phi0, dphi0);
+
+        assert!(phi <= phi0 + C1 * alpha * dphi0);
+        assert!(ray.slope(alpha).abs() <= -c2 * dphi0);
+    }
+
+    #[test]
+    fn test_goldstein() {
+        let (x, p) = start();
+        let c = 0.25;
+        let derivatives = Derivatives::automatic::<Rosenbrok>();
+        let mut objective = derivatives.of(Rosenbrok::f);
+        let mut ray = Ray::new(&mut objective, x, p);
+        let (phi0, dphi0) = (ray.value(0.0), ray.slope(0.0));
+
+        let (alpha, phi, _) = LineSearch::goldstein(c)
+            .with_initial_step(1e-5)
+            .search(&mut ray, phi0, dphi0);
+
+        assert!(phi <= phi0 + c * alpha * dphi0);
+        assert!(phi >= phi0 + (1.0 - c) * alpha * dphi0);
+    }
+
+    #[test_case(Booth; "booth")]
+    #[test_case(Himmelblau; "himmelblau")]
+    fn test_gauss_zeidel<F: Function<2>>(f: F) {
+        Task::new(
+            GaussZeidel::new(
+                [-1.0, 1.0].into(),
+                LineSearch::strong_wolfe(C1, 0.1).into(),
+                1e-12,
+                1e-14,
+            ),
+            f,
+        )
+        .solve_space_check()
+        .with_eps_x(1e-4)
+        .check();
+    }
+
+    #[test_case(LineSearch::armijo(C1, 0.5); "armijo")]
+    #[test_case(LineSearch::strong_wolfe(C1, 0.9); "strong_wolfe")]
+    #[test_case(LineSearch::goldstein(0.25); "goldstein")]
+    fn test_gradient_descent_rosenbrok(search: LineSearch) {
+        Task::new(
+            GradientDescent::new(
+                [-1.2, 1.0].into(),
+                Derivatives::Numeric(Difference::Richardson),
+                Step::Inexact(search),
+                1e-8,
+                0.0,
+                0.0,
+            ),
+            Rosenbrok,
+        )
+        .solve_space_check()
+        .with_eps_x(1e-4)
+        .check();
+    }
+}
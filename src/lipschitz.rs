@@ -0,0 +1,193 @@
+use crate::method::{Optimizer, Steps};
+use derive_more::Constructor;
+use ordered_float::OrderedFloat;
+use std::ops::RangeInclusive;
+
+/// Sorted trial points `(x, f(x))` shared by the Lipschitz methods.
+struct Trials(Vec<(f64, f64)>);
+
+impl Trials {
+    fn new(range: &RangeInclusive<f64>, f: &mut impl FnMut(f64) -> f64) -> Self {
+        let a = *range.start();
+        let b = *range.end();
+        Self(vec![(a, f(a)), (b, f(b))])
+    }
+
+    fn insert(&mut self, x: f64, y: f64) {
+        let i = self.0.partition_point(|&(t, _)| t < x);
+        self.0.insert(i, (x, y));
+    }
+
+    fn intervals(&self) -> impl Iterator<Item = ((f64, f64), (f64, f64))> + '_ {
+        self.0.windows(2).map(|w| (w[0], w[1]))
+    }
+
+    /// Interval with the lowest `bound` together with that bound.
+    fn lowest(
+        &self,
+        bound: impl Fn((f64, f64), (f64, f64)) -> f64,
+    ) -> (f64, (f64, f64), (f64, f64)) {
+        self.intervals()
+            .map(|(a, b)| (bound(a, b), a, b))
+            .min_by_key(|(bound, _, _)| OrderedFloat(*bound))
+            .unwrap()
+    }
+
+    fn best(&self) -> (f64, f64) {
+        *self.0.iter().min_by_key(|(_, y)| OrderedFloat(*y)).unwrap()
+    }
+}
+
+/// Piyavskii–Shubert method: refines the interval with the lowest saw-tooth minorant built
+/// from the known Lipschitz constant `l`. Stops once the best value is within `eps` of the
+/// minorant's minimum, which is a lower bound on the global minimum.
+#[derive(Constructor, Clone)]
+pub struct Piyavskii {
+    range: RangeInclusive<f64>,
+    l: f64,
+    eps: f64,
+    max_steps: usize,
+}
+
+impl Optimizer for Piyavskii {
+    type X = f64;
+    type F = f64;
+    type Metadata = Steps;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let mut trials = Trials::new(&self.range, &mut f);
+        let mut r = 0;
+
+        while r < self.max_steps {
+            let (bound, (x0, y0), (x1, y1)) =
+                trials.lowest(|(x0, y0), (x1, y1)| (y0 + y1) / 2.0 - self.l * (x1 - x0) / 2.0);
+
+            if trials.best().1 - bound < self.eps {
+                break;
+            }
+
+            let x = ((x0 + x1) / 2.0 - (y1 - y0) / (2.0 * self.l)).clamp(x0, x1);
+            trials.insert(x, f(x));
+            r += 1;
+        }
+
+        let (x, y) = trials.best();
+        (x, y, Steps(r))
+    }
+}
+
+/// Strongin's information-statistical algorithm with the Lipschitz constant estimated from the
+/// trials and scaled by the reliability parameter `r > 1`. Stops once the best value is within
+/// `eps` of the lower estimate given by the best interval's characteristic.
+#[derive(Constructor, Clone)]
+pub struct Strongin {
+    range: RangeInclusive<f64>,
+    r: f64,
+    eps: f64,
+    max_steps: usize,
+}
+
+impl Optimizer for Strongin {
+    type X = f64;
+    type F = f64;
+    type Metadata = Steps;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let mut trials = Trials::new(&self.range, &mut f);
+        let mut r = 0;
+
+        while r < self.max_steps {
+            let big_m = trials
+                .intervals()
+                .map(|((x0, y0), (x1, y1))| (y1 - y0).abs() / (x1 - x0))
+                .fold(0.0, f64::max);
+            let m = if big_m > 0.0 { self.r * big_m } else { 1.0 };
+
+            // Minus a quarter of Strongin's characteristic, so the best interval has the lowest bound.
+            let (bound, (x0, y0), (x1, y1)) = trials.lowest(|(x0, y0), (x1, y1)| {
+                let delta = x1 - x0;
+                (y0 + y1) / 2.0 - m * delta / 4.0 - (y1 - y0).powi(2) / (4.0 * m * delta)
+            });
+
+            if trials.best().1 - bound < self.eps {
+                break;
+            }
+
+            let x = ((x0 + x1) / 2.0 - (y1 - y0) / (2.0 * m)).clamp(x0, x1);
+            trials.insert(x, f(x));
+            r += 1;
+        }
+
+        let (x, y) = trials.best();
+        (x, y, Steps(r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compound::NestedTasks;
+    use crate::functions::{Function, Rastrigin, Sphere, Tang};
+    use crate::lipschitz::{Piyavskii, Strongin};
+    use crate::method::Optimizer;
+    use crate::task::Task;
+    use std::rc::Rc;
+    use test_case::test_case;
+
+    #[test]
+    fn test_piyavskii_tang() {
+        Task::new(Piyavskii::new(-5.0..=5.0, 200.0, 1e-4, 100000), Tang)
+            .solve_check()
+            .check();
+    }
+
+    #[test]
+    fn test_piyavskii_rastrigin() {
+        Task::new(Piyavskii::new(-5.12..=5.12, 80.0, 1e-5, 100000), Rastrigin)
+            .solve_check()
+            .check();
+    }
+
+    #[test]
+    fn test_piyavskii_bound_gap() {
+        let (_, y, _) =
+            Piyavskii::new(-5.0..=5.0, 200.0, 0.5, 100000).optimize(|x| Tang::f([x].into()));
+
+        assert!(y - Tang::<1>::F < 0.5);
+    }
+
+    #[test]
+    fn test_strongin_tang() {
+        Task::new(Strongin::new(-5.0..=5.0, 2.0, 1e-4, 100000), Tang)
+            .solve_check()
+            .check();
+    }
+
+    #[test]
+    fn test_strongin_rastrigin() {
+        Task::new(Strongin::new(-5.12..=5.12, 2.0, 1e-5, 100000), Rastrigin)
+            .solve_check()
+            .check();
+    }
+
+    #[test_case(Rastrigin)]
+    #[test_case(Tang)]
+    #[test_case(Sphere)]
+    fn test_nested_strongin<F: Function<2>>(f: F) {
+        let optimizer = NestedTasks::new(
+            vec![-5.0..=5.0, -5.0..=5.0],
+            Rc::new(|r| Strongin::new(r, 2.0, 1e-2, 10000).into()),
+        );
+
+        Task::new(optimizer, f)
+            .solve_space_check()
+            .with_eps_x(1e-2)
+            .with_eps_y(1e-2)
+            .check();
+    }
+}
@@ -1,10 +1,17 @@
 use crate::approx_model::ApproxModel;
 use crate::binary::Binary;
+use crate::bracket::{Bracketed, Swann};
+use crate::brent::Brent;
 use crate::enumerate::MonteCarlo;
-use crate::fibonacci::GoldenRatio;
+use crate::fibonacci::{Fibonacci, GoldenRatio};
 use crate::functions::Point;
+use crate::hooke_jeeves::HookeJeeves;
+use crate::line_search::LineSearch;
+use crate::lipschitz::{Piyavskii, Strongin};
+use crate::nelder_mead::NelderMead;
 use crate::zeidel::GaussZeidel;
 use derive_more::From;
+use std::ops::RangeInclusive;
 
 pub trait Optimizer {
     type X;
@@ -17,22 +24,65 @@ pub trait Optimizer {
 #[derive(Debug)]
 pub struct Steps(pub usize);
 
+#[derive(Debug)]
+pub struct Evaluations(pub usize);
+
 #[derive(Clone, From)]
 pub enum OneDimensionalMethod {
     GoldenRatio(GoldenRatio),
+    Fibonacci(Fibonacci),
     Binary(Binary),
     ApproxModel(ApproxModel),
+    Brent(Brent),
+    LineSearch(LineSearch),
+    #[from(skip)]
+    Bracketed(Box<Bracketed>),
 }
 
 #[derive(From)]
 pub enum GlobalOneDimensionalMethod {
     MonteCarlo(MonteCarlo<1>),
     ApproxModel(ApproxModel),
+    Piyavskii(Piyavskii),
+    Strongin(Strongin),
 }
 
 #[derive(From)]
 pub enum GlobalMultiMethod<const N: usize> {
     GaussZeidel(GaussZeidel<N>),
+    NelderMead(NelderMead<N>),
+    HookeJeeves(HookeJeeves<N>),
+}
+
+impl From<Bracketed> for OneDimensionalMethod {
+    fn from(value: Bracketed) -> Self {
+        OneDimensionalMethod::Bracketed(Box::new(value))
+    }
+}
+
+impl OneDimensionalMethod {
+    /// Line searches are not confined to a range and are returned unchanged.
+    pub fn with_range(self, range: RangeInclusive<f64>) -> Self {
+        match self {
+            OneDimensionalMethod::GoldenRatio(x) => x.with_range(range).into(),
+            OneDimensionalMethod::Fibonacci(x) => x.with_range(range).into(),
+            OneDimensionalMethod::Binary(x) => x.with_range(range).into(),
+            OneDimensionalMethod::ApproxModel(x) => x.with_range(range).into(),
+            OneDimensionalMethod::Brent(x) => x.with_range(range).into(),
+            OneDimensionalMethod::LineSearch(x) => x.into(),
+            OneDimensionalMethod::Bracketed(x) => x.with_range(range).into(),
+        }
+    }
+
+    /// Searches for an interval containing a minimum around `start` before every run. Line
+    /// searches have no range to bracket and are rejected.
+    pub fn auto_bracket(self, start: f64, step: f64, max_steps: usize) -> Self {
+        assert!(
+            !matches!(self, OneDimensionalMethod::LineSearch(_)),
+            "A line search has no range to bracket"
+        );
+        Bracketed::new(Swann::new(start, step, max_steps), self).into()
+    }
 }
 
 impl Optimizer for OneDimensionalMethod {
@@ -43,8 +93,15 @@ impl Optimizer for OneDimensionalMethod {
     fn optimize(&self, f: impl FnMut(Self::X) -> Self::F) -> (Self::X, Self::F, Self::Metadata) {
         match self {
             OneDimensionalMethod::GoldenRatio(x) => x.optimize(f),
+            OneDimensionalMethod::Fibonacci(x) => x.optimize(f),
             OneDimensionalMethod::Binary(x) => x.optimize(f),
             OneDimensionalMethod::ApproxModel(x) => x.optimize(f),
+            OneDimensionalMethod::Brent(x) => {
+                let (x, f, (steps, _)) = x.optimize(f);
+                (x, f, steps)
+            }
+            OneDimensionalMethod::LineSearch(x) => x.optimize(f),
+            OneDimensionalMethod::Bracketed(x) => x.optimize(f),
         }
     }
 }
@@ -67,6 +124,14 @@ impl Optimizer for GlobalOneDimensionalMethod {
                 let (x, f, _) = x.optimize(|it| f([it].into()));
                 ([x].into(), f, ())
             }
+            GlobalOneDimensionalMethod::Piyavskii(x) => {
+                let (x, f, _) = x.optimize(|it| f([it].into()));
+                ([x].into(), f, ())
+            }
+            GlobalOneDimensionalMethod::Strongin(x) => {
+                let (x, f, _) = x.optimize(|it| f([it].into()));
+                ([x].into(), f, ())
+            }
         }
     }
 }
@@ -79,6 +144,8 @@ impl<const N: usize> Optimizer for GlobalMultiMethod<N> {
     fn optimize(&self, f: impl FnMut(Self::X) -> Self::F) -> (Self::X, Self::F, Self::Metadata) {
         match self {
             GlobalMultiMethod::GaussZeidel(x) => x.optimize(f),
+            GlobalMultiMethod::NelderMead(x) => x.optimize(f),
+            GlobalMultiMethod::HookeJeeves(x) => x.optimize(f),
         }
     }
 }
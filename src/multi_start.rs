@@ -0,0 +1,373 @@
+use crate::functions::Point;
+use crate::method::Optimizer;
+use nalgebra::SVector;
+use ordered_float::OrderedFloat;
+use rand::seq::SliceRandom;
+use rand::{RngExt, rng};
+use std::ops::RangeInclusive;
+
+/// Design of the start points in the unit cube.
+#[derive(Debug, Clone, Copy)]
+pub enum Sampling {
+    Uniform,
+    /// One point in every one of `n` equal slices of each coordinate.
+    LatinHypercube,
+    /// Deterministic low-discrepancy sequence, available for up to 10 dimensions.
+    Sobol,
+}
+
+/// Joe–Kuo primitive polynomials `(s, a)` and initial direction numbers for dimensions 2 to 10.
+const SOBOL: [(u32, u32, &[u32]); 9] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+];
+
+const BITS: usize = 32;
+
+fn directions(dimension: usize) -> [u32; BITS] {
+    let mut v = [0u32; BITS];
+    if dimension == 0 {
+        for (k, v) in v.iter_mut().enumerate() {
+            *v = 1 << (BITS - 1 - k);
+        }
+        return v;
+    }
+
+    let (s, a, m) = SOBOL[dimension - 1];
+    let s = s as usize;
+    for k in 0..BITS {
+        v[k] = if k < s {
+            m[k] << (BITS - 1 - k)
+        } else {
+            let mut value = v[k - s] ^ (v[k - s] >> s);
+            for j in 1..s {
+                if (a >> (s - 1 - j)) & 1 == 1 {
+                    value ^= v[k - j];
+                }
+            }
+            value
+        };
+    }
+    v
+}
+
+impl Sampling {
+    fn sample<const N: usize>(&self, n: usize) -> Vec<Point<N>> {
+        let mut random = rng();
+        match self {
+            Sampling::Uniform => (0..n).map(|_| Point::new_random()).collect(),
+            Sampling::LatinHypercube => {
+                let mut points = vec![Point::zeros(); n];
+                for j in 0..N {
+                    let mut slices: Vec<usize> = (0..n).collect();
+                    slices.shuffle(&mut random);
+                    for (point, slice) in points.iter_mut().zip(slices) {
+                        point[j] = (slice as f64 + random.random::<f64>()) / n as f64;
+                    }
+                }
+                points
+            }
+            Sampling::Sobol => {
+                assert!(
+                    N <= SOBOL.len() + 1,
+                    "Sobol sampling supports up to 10 dimensions"
+                );
+                let v: Vec<_> = (0..N).map(directions).collect();
+                let mut x = [0u32; N];
+
+                // Gray code order, starting from the origin to keep the balance of the first
+                // 2^k points
+                (0..n)
+                    .map(|i| {
+                        if i > 0 {
+                            let c = (i - 1).trailing_ones() as usize;
+                            for j in 0..N {
+                                x[j] ^= v[j][c];
+                            }
+                        }
+                        Point::from_fn(|j, _| x[j] as f64 / 2f64.powi(BITS as i32))
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Basin hopping: every found minimum is perturbed uniformly within `step` `hops`
+/// times and the local search restarted, the new minimum replaces the current one by the
+/// Metropolis criterion at `temperature`.
+#[derive(Debug, Clone, Copy)]
+pub struct BasinHopping {
+    pub hops: usize,
+    pub step: f64,
+    pub temperature: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Minimum<const N: usize> {
+    pub x: Point<N>,
+    pub f: f64,
+    /// Local searches that ended in this minimum.
+    pub hits: usize,
+}
+
+#[derive(Debug)]
+pub struct MultiStartInfo<const N: usize> {
+    /// Distinct minima in ascending order of value.
+    pub minima: Vec<Minimum<N>>,
+    pub evaluations: usize,
+}
+
+/// Runs a local method from many start points in a box and collects the distinct minima it
+/// reaches. Results closer than `radius` are merged into one minimum.
+pub struct MultiStart<const N: usize, O> {
+    bounds: SVector<RangeInclusive<f64>, N>,
+    starts: usize,
+    sampling: Sampling,
+    builder: Box<dyn Fn(Point<N>) -> O>,
+    hopping: Option<BasinHopping>,
+    radius: f64,
+}
+
+impl<const N: usize, O: Optimizer<X = Point<N>, F = f64>> MultiStart<N, O> {
+    pub fn new<G: Fn(Point<N>) -> O + 'static>(
+        bounds: [RangeInclusive<f64>; N],
+        starts: usize,
+        sampling: Sampling,
+        builder: G,
+        radius: f64,
+    ) -> Self {
+        assert!(starts > 0, "At least one start point is needed");
+
+        Self {
+            bounds: bounds.into(),
+            starts,
+            sampling,
+            builder: Box::new(builder),
+            hopping: None,
+            radius,
+        }
+    }
+
+    pub fn with_basin_hopping(self, hopping: BasinHopping) -> Self {
+        Self {
+            hopping: Some(hopping),
+            ..self
+        }
+    }
+
+    fn clamp(&self, x: Point<N>) -> Point<N> {
+        x.zip_map(&self.bounds, |x, range| {
+            x.clamp(*range.start(), *range.end())
+        })
+    }
+
+    fn record(&self, minima: &mut Vec<Minimum<N>>, x: Point<N>, f: f64) {
+        match minima.iter_mut().find(|m| (m.x - x).norm() < self.radius) {
+            Some(minimum) => {
+                minimum.hits += 1;
+                if f < minimum.f {
+                    (minimum.x, minimum.f) = (x, f);
+                }
+            }
+            None => minima.push(Minimum { x, f, hits: 1 }),
+        }
+    }
+}
+
+impl<const N: usize, O: Optimizer<X = Point<N>, F = f64>> Optimizer for MultiStart<N, O> {
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = MultiStartInfo<N>;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let mut random = rng();
+        let map_to = |x, y: RangeInclusive<f64>| (y.end() - y.start()) * x + y.start();
+
+        let mut evaluations = 0;
+        let mut counted = |x| {
+            evaluations += 1;
+            f(x)
+        };
+        let mut local = |start| (self.builder)(start).optimize(&mut counted);
+        let mut minima = vec![];
+
+        for unit in self.sampling.sample::<N>(self.starts) {
+            let (mut x, mut fx, _) = local(unit.zip_map(&self.bounds, map_to));
+            self.record(&mut minima, x, fx);
+
+            let Some(hopping) = self.hopping else {
+                continue;
+            };
+            for _ in 0..hopping.hops {
+                let step =
+                    Point::<N>::from_fn(|_, _| random.random_range(-hopping.step..=hopping.step));
+                let (y, fy, _) = local(self.clamp(x + step));
+                self.record(&mut minima, y, fy);
+
+                let accept =
+                    fy <= fx || random.random::<f64>() < ((fx - fy) / hopping.temperature).exp();
+                if accept {
+                    (x, fx) = (y, fy);
+                }
+            }
+        }
+
+        minima.sort_by_key(|m| OrderedFloat(m.f));
+        let (x, fx) = (minima[0].x, minima[0].f);
+        let info = MultiStartInfo {
+            minima,
+            evaluations,
+        };
+        (x, fx, info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::functions::{Function, Himmelblau, Point, Rastrigin};
+    use crate::hooke_jeeves::HookeJeeves;
+    use crate::method::Optimizer;
+    use crate::multi_start::{BasinHopping, MultiStart, Sampling};
+    use crate::nelder_mead::NelderMead;
+    use crate::task::Task;
+    use approx::assert_relative_eq;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use test_case::test_case;
+
+    #[test_case(Sampling::Uniform; "uniform")]
+    #[test_case(Sampling::LatinHypercube; "latin_hypercube")]
+    #[test_case(Sampling::Sobol; "sobol")]
+    fn test_himmelblau(sampling: Sampling) {
+        let optimizer = MultiStart::new(
+            [-5.0..=5.0, -5.0..=5.0],
+            40,
+            sampling,
+            |start| NelderMead::new(start, 0.5, 1e-12),
+            1e-3,
+        );
+        let (_, _, info) = optimizer.optimize(Himmelblau::f);
+
+        // Local maximum and saddles are not reached by a descent method
+        assert_eq!(info.minima.len(), 4, "{:?}", info.minima);
+        for minimum in &info.minima {
+            assert_relative_eq!(minimum.f, Himmelblau::F, epsilon = 1e-8);
+            assert!(
+                Himmelblau::X()
+                    .iter()
+                    .any(|x| (x - minimum.x).norm() < 1e-5)
+            );
+        }
+        assert_eq!(info.minima.iter().map(|m| m.hits).sum::<usize>(), 40);
+
+        Task::new(optimizer, Himmelblau).solve_space_check().check();
+    }
+
+    #[test]
+    fn test_sobol_uniformity() {
+        let points = Sampling::Sobol.sample::<3>(64);
+
+        // Every octant of the unit cube gets the same number of points
+        let mut octants = [0; 8];
+        for p in &points {
+            let octant = p.iter().fold(0, |acc, &x| 2 * acc + (x >= 0.5) as usize);
+            octants[octant] += 1;
+        }
+        assert_eq!(octants, [8; 8]);
+        assert!(
+            points
+                .iter()
+                .all(|p| p.iter().all(|x| (0.0..1.0).contains(x)))
+        );
+    }
+
+    #[test]
+    fn test_sobol_sequence() {
+        let expected = [
+            [0.0, 0.0],
+            [0.5, 0.5],
+            [0.75, 0.25],
+            [0.25, 0.75],
+            [0.375, 0.375],
+            [0.875, 0.875],
+            [0.625, 0.125],
+            [0.125, 0.625],
+        ];
+
+        for (point, expected) in Sampling::Sobol.sample::<2>(8).into_iter().zip(expected) {
+            assert_eq!(point, Point::from(expected));
+        }
+    }
+
+    #[test]
+    fn test_latin_hypercube() {
+        let n = 10;
+        let points = Sampling::LatinHypercube.sample::<2>(n);
+
+        for j in 0..2 {
+            let mut slices: Vec<usize> =
+                points.iter().map(|p| (p[j] * n as f64) as usize).collect();
+            slices.sort();
+            assert_eq!(slices, (0..n).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_basin_hopping() {
+        let optimizer = MultiStart::new(
+            [-5.12..=5.12, -5.12..=5.12],
+            2,
+            Sampling::Uniform,
+            |start: Point<2>| HookeJeeves::new(start, 0.5, 0.5, 1e-10),
+            1e-3,
+        )
+        .with_basin_hopping(BasinHopping {
+            hops: 100,
+            step: 1.0,
+            temperature: 1.0,
+        });
+
+        Task::new(optimizer, Rastrigin).solve_space_check().check();
+    }
+
+    #[test]
+    fn test_hops_start_inside_box() {
+        let starts = Rc::new(RefCell::new(vec![]));
+        let recorded = starts.clone();
+        let optimizer = MultiStart::new(
+            [-1.0..=1.0, -1.0..=1.0],
+            3,
+            Sampling::Uniform,
+            move |start: Point<2>| {
+                recorded.borrow_mut().push(start);
+                HookeJeeves::new(start, 0.5, 0.5, 1e-10)
+            },
+            1e-3,
+        )
+        .with_basin_hopping(BasinHopping {
+            hops: 20,
+            step: 5.0,
+            temperature: 1.0,
+        });
+        optimizer.optimize(Rastrigin::f);
+
+        assert_eq!(starts.borrow().len(), 3 * 21);
+        assert!(
+            starts
+                .borrow()
+                .iter()
+                .all(|x| x.iter().all(|x| (-1.0..=1.0).contains(x)))
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use crate::functions::Point;
+use crate::method::{Optimizer, Steps};
+use nalgebra::DMatrix;
+use ordered_float::OrderedFloat;
+
+#[derive(Clone, Copy)]
+pub struct Coefficients {
+    pub reflection: f64,
+    pub expansion: f64,
+    pub contraction: f64,
+    pub shrink: f64,
+}
+
+impl Default for Coefficients {
+    fn default() -> Self {
+        Self {
+            reflection: 1.0,
+            expansion: 2.0,
+            contraction: 0.5,
+            shrink: 0.5,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct NelderMead<const N: usize> {
+    start: Point<N>,
+    scale: f64,
+    coefficients: Coefficients,
+    eps: f64,
+}
+
+impl<const N: usize> NelderMead<N> {
+    /// Relative volume below which the simplex is considered degenerate and rebuilt.
+    const DEGENERACY: f64 = 1e-10;
+
+    pub fn new(start: Point<N>, scale: f64, eps: f64) -> Self {
+        Self {
+            start,
+            scale,
+            coefficients: Coefficients::default(),
+            eps,
+        }
+    }
+
+    pub fn with_coefficients(self, coefficients: Coefficients) -> Self {
+        Self {
+            coefficients,
+            ..self
+        }
+    }
+
+    fn simplex(
+        x: Point<N>,
+        scale: f64,
+        f: &mut impl FnMut(Point<N>) -> f64,
+    ) -> Vec<(Point<N>, f64)> {
+        let mut simplex = vec![(x, f(x))];
+        for i in 0..N {
+            let mut p = x;
+            p[i] += scale;
+            simplex.push((p, f(p)));
+        }
+        simplex
+    }
+
+    fn diameter(simplex: &[(Point<N>, f64)]) -> f64 {
+        simplex
+            .iter()
+            .map(|(p, _)| (p - simplex[0].0).norm())
+            .fold(0.0, f64::max)
+    }
+
+    fn is_degenerate(simplex: &[(Point<N>, f64)]) -> bool {
+        let diameter = Self::diameter(simplex);
+        if diameter == 0.0 {
+            return true;
+        }
+
+        let edges = DMatrix::from_fn(N, N, |i, j| {
+            (simplex[j + 1].0[i] - simplex[0].0[i]) / diameter
+        });
+        edges.determinant().abs() < Self::DEGENERACY
+    }
+}
+
+impl<const N: usize> Optimizer for NelderMead<N> {
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = Steps;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let Coefficients {
+            reflection,
+            expansion,
+            contraction,
+            shrink,
+        } = self.coefficients;
+        let mut simplex = Self::simplex(self.start, self.scale, &mut f);
+        let mut r = 0;
+
+        loop {
+            simplex.sort_by_key(|(_, y)| OrderedFloat(*y));
+            let (best, f_best) = simplex[0];
+            let (worst, f_worst) = simplex[N];
+
+            if (f_worst - f_best).abs() < self.eps && Self::diameter(&simplex) < self.eps {
+                break;
+            }
+
+            if Self::is_degenerate(&simplex) {
+                let scale = Self::diameter(&simplex).max(self.eps);
+                simplex = Self::simplex(best, scale, &mut f);
+                r += 1;
+                continue;
+            }
+
+            let centroid = simplex[..N].iter().map(|(p, _)| p).sum::<Point<N>>() / N as f64;
+            let f_second = simplex[N - 1].1;
+
+            let xr = centroid + reflection * (centroid - worst);
+            let fr = f(xr);
+
+            if fr < f_best {
+                let xe = centroid + expansion * (xr - centroid);
+                let fe = f(xe);
+                simplex[N] = if fe < fr { (xe, fe) } else { (xr, fr) };
+            } else if fr < f_second {
+                simplex[N] = (xr, fr);
+            } else {
+                let xc = if fr < f_worst {
+                    centroid + contraction * (xr - centroid)
+                } else {
+                    centroid + contraction * (worst - centroid)
+                };
+                let fc = f(xc);
+
+                if fc < fr.min(f_worst) {
+                    simplex[N] = (xc, fc);
+                } else {
+                    for (p, y) in simplex.iter_mut().skip(1) {
+                        *p = best + shrink * (*p - best);
+                        *y = f(*p);
+                    }
+                }
+            }
+            r += 1;
+        }
+
+        let (x, y) = simplex[0];
+        (x, y, Steps(r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::functions::{Booth, Function, Himmelblau, Rosenbrok, Sphere};
+    use crate::iterative_conditional::{IterativeConditional, Parameters};
+    use crate::nelder_mead::{Coefficients, NelderMead};
+    use crate::restriction::Restriction;
+    use crate::task::Task;
+    use test_case::test_case;
+
+    #[test_case(Booth; "booth")]
+    #[test_case(Sphere; "sphere")]
+    #[test_case(Himmelblau; "himmelblau")]
+    #[test_case(Rosenbrok; "rosenbrok")]
+    fn test<F: Function<2>>(f: F) {
+        Task::new(NelderMead::new([-1.2, 1.0].into(), 1.0, 1e-12), f)
+            .solve_space_check()
+            .check();
+    }
+
+    #[test]
+    fn test_nelder_mead_coefficients() {
+        let coefficients = Coefficients {
+            reflection: 1.0,
+            expansion: 1.5,
+            contraction: 0.75,
+            shrink: 0.5,
+        };
+
+        Task::new(
+            NelderMead::new([-1.2, 1.0].into(), 0.5, 1e-12).with_coefficients(coefficients),
+            Rosenbrok,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test]
+    fn test_nelder_mead_iterative_conditional() {
+        struct Func;
+
+        impl Function<2> for Func {
+            const F: f64 = 0.5;
+
+            fn X() -> Vec<crate::functions::Point<2>> {
+                vec![[2.5, 1.5].into()]
+            }
+
+            fn f(xs: crate::functions::Point<2>) -> f64 {
+                (xs[0] - 3.0).powi(2) + (xs[1] - 2.0).powi(2)
+            }
+        }
+
+        let restriction = Restriction::equality(|xs| xs[0] + xs[1] - 4.0);
+        let method = IterativeConditional::new(
+            vec![restriction.clone()],
+            [-4.0, 2.3].into(),
+            |start| NelderMead::new(start, 1.0, 1e-15).into(),
+            1e-6,
+            Parameters {
+                lambda: vec![1.0],
+                mu: vec![],
+                alpha_h: 1.0,
+                alpha_g: 1.0,
+            },
+            |p| p.alpha_h *= 4.0,
+        )
+        .unwrap();
+
+        Task::new(method, Func)
+            .solve_space_check()
+            .satisfy_restrictions(vec![restriction])
+            .check();
+    }
+}
@@ -0,0 +1,194 @@
+use crate::derivatives::Derivatives;
+use crate::functions::Point;
+use crate::line_search::Search;
+use crate::method::{Optimizer, Steps};
+use nalgebra::{Cholesky, Const, SMatrix};
+
+/// Damped Newton method. A Hessian which is not positive definite is shifted by `tau * I`,
+/// with `tau` grown from `beta` until the Cholesky factorization succeeds. When no finite shift
+/// helps, e.g. the Hessian holds NaN, a steepest descent step is taken instead.
+#[derive(Clone)]
+pub struct Newton<const N: usize> {
+    start: Point<N>,
+    derivatives: Derivatives<N>,
+    search: Search,
+    beta: f64,
+    eps: f64,
+    max_steps: usize,
+}
+
+impl<const N: usize> Newton<N> {
+    pub fn new(
+        start: Point<N>,
+        derivatives: Derivatives<N>,
+        search: impl Into<Search>,
+        eps: f64,
+    ) -> Self {
+        Self {
+            start,
+            derivatives,
+            search: search.into(),
+            beta: 1e-3,
+            eps,
+            max_steps: 10_000,
+        }
+    }
+
+    pub fn with_shift(self, beta: f64) -> Self {
+        assert!(beta > 0.0, "The initial shift must be positive");
+        Self { beta, ..self }
+    }
+
+    pub fn with_max_steps(self, max_steps: usize) -> Self {
+        Self { max_steps, ..self }
+    }
+
+    fn factorize(&self, hessian: SMatrix<f64, N, N>) -> Option<Cholesky<f64, Const<N>>> {
+        if !hessian.iter().all(|h| h.is_finite()) {
+            return None;
+        }
+
+        let min_diagonal = hessian.diagonal().min();
+        let mut tau = if min_diagonal > 0.0 {
+            0.0
+        } else {
+            self.beta - min_diagonal
+        };
+
+        while tau.is_finite() {
+            let shifted = hessian + SMatrix::<f64, N, N>::identity() * tau;
+            if let Some(cholesky) = Cholesky::new(shifted) {
+                return Some(cholesky);
+            }
+            tau = (2.0 * tau).max(self.beta);
+        }
+        None
+    }
+}
+
+impl<const N: usize> Optimizer for Newton<N> {
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = Steps;
+
+    fn optimize(&self, f: impl FnMut(Self::X) -> Self::F) -> (Self::X, Self::F, Self::Metadata) {
+        let mut objective = self.derivatives.of(f);
+        let mut x = self.start;
+        let (mut fx, mut gradient) = objective.value_gradient(x);
+        let mut r = 0;
+
+        while r < self.max_steps && gradient.norm() >= self.eps {
+            let direction = match self.factorize(objective.hessian(x)) {
+                Some(cholesky) => cholesky.solve(&-gradient),
+                None => -gradient,
+            };
+            let slope = gradient.dot(&direction);
+
+            let (alpha, f_next) = self
+                .search
+                .step(&mut objective, x, direction, (fx, slope), 1.0);
+            if alpha == 0.0 {
+                break;
+            }
+
+            x += alpha * direction;
+            fx = f_next;
+            gradient = objective.gradient(x);
+            r += 1;
+        }
+
+        (x, fx, Steps(r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::brent::Brent;
+    use crate::derivatives::{Derivatives, Difference};
+    use crate::functions::{Booth, Function, GenericFunction, Himmelblau, Rosenbrok, Sphere};
+    use crate::line_search::LineSearch;
+    use crate::method::{OneDimensionalMethod, Optimizer, Steps};
+    use crate::newton::Newton;
+    use crate::task::Task;
+    use nalgebra::SMatrix;
+    use test_case::test_case;
+
+    #[test_case(Rosenbrok; "rosenbrok")]
+    #[test_case(Booth; "booth")]
+    #[test_case(Himmelblau; "himmelblau")]
+    #[test_case(Sphere; "sphere")]
+    fn test_automatic<F: GenericFunction<2> + 'static>(f: F) {
+        Task::new(
+            Newton::new(
+                [-1.2, 1.0].into(),
+                Derivatives::automatic::<F>(),
+                LineSearch::armijo(1e-4, 0.5),
+                1e-10,
+            ),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test_case(Rosenbrok; "rosenbrok")]
+    #[test_case(Himmelblau; "himmelblau")]
+    fn test_finite_differences<F: Function<2>>(f: F) {
+        let method = OneDimensionalMethod::from(Brent::new(0.0..=1.0, 1e-12));
+
+        Task::new(
+            Newton::new(
+                [-1.2, 1.0].into(),
+                Derivatives::Numeric(Difference::Richardson),
+                method.auto_bracket(0.0, 0.1, 60),
+                1e-6,
+            ),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test]
+    fn test_indefinite_hessian() {
+        // The Hessian of Himmelblau's function is negative definite near its local maximum
+        let optimizer = Newton::new(
+            [-0.3, -0.9].into(),
+            Derivatives::automatic::<Himmelblau>(),
+            LineSearch::strong_wolfe(1e-4, 0.9),
+            1e-10,
+        );
+        let (_, y, _) = optimizer.optimize(Himmelblau::f);
+
+        approx::assert_abs_diff_eq!(y, 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_nan_hessian() {
+        let derivatives =
+            Derivatives::exact(|x| 2.0 * x).with_hessian(|_| SMatrix::repeat(f64::NAN));
+        let optimizer = Newton::new(
+            [3.0, -2.0].into(),
+            derivatives,
+            LineSearch::armijo(1e-4, 0.5),
+            1e-10,
+        );
+        let (x, _, _) = optimizer.optimize(Sphere::f);
+
+        approx::assert_abs_diff_eq!(x, Sphere::X()[0], epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_quadratic_in_one_step() {
+        let optimizer = Newton::new(
+            [-40.0, 25.0].into(),
+            Derivatives::automatic::<Booth>(),
+            LineSearch::armijo(1e-4, 0.5),
+            1e-10,
+        );
+        let (x, _, Steps(r)) = optimizer.optimize(Booth::f);
+
+        assert_eq!(r, 1);
+        approx::assert_relative_eq!(x, Booth::X()[0], epsilon = 1e-12);
+    }
+}
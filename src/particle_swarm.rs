@@ -0,0 +1,308 @@
+use crate::functions::Point;
+use crate::method::Optimizer;
+use nalgebra::SVector;
+use ordered_float::OrderedFloat;
+use rand::{RngExt, rng};
+use std::ops::RangeInclusive;
+
+/// Velocity update `v = a (v + c1 r1 (p - x) + c2 r2 (g - x))` with personal best `p`,
+/// neighbourhood best `g` and uniform random `r1`, `r2`.
+#[derive(Debug, Clone, Copy)]
+pub enum Velocity {
+    /// Inertia weight `w` applied to the previous velocity only.
+    Inertia { w: f64, c1: f64, c2: f64 },
+    /// Clerc's constriction factor applied to the whole update, requires `c1 + c2 > 4`.
+    Constriction { c1: f64, c2: f64 },
+}
+
+impl Velocity {
+    fn update<const N: usize>(
+        &self,
+        v: &Point<N>,
+        to_personal: &Point<N>,
+        to_neighbourhood: &Point<N>,
+    ) -> Point<N> {
+        let r1 = Point::<N>::new_random();
+        let r2 = Point::<N>::new_random();
+        let attraction = |c1: f64, c2: f64| {
+            c1 * r1.component_mul(to_personal) + c2 * r2.component_mul(to_neighbourhood)
+        };
+
+        match *self {
+            Velocity::Inertia { w, c1, c2 } => w * v + attraction(c1, c2),
+            Velocity::Constriction { c1, c2 } => {
+                let phi = c1 + c2;
+                let chi = 2.0 / (2.0 - phi - (phi * phi - 4.0 * phi).sqrt()).abs();
+                chi * (v + attraction(c1, c2))
+            }
+        }
+    }
+}
+
+/// Particles whose personal bests inform each other.
+#[derive(Debug, Clone, Copy)]
+pub enum Topology {
+    Global,
+    /// Local best among the `k` nearest indices on each side of a ring, a radius of half the
+    /// swarm or more covers all of it.
+    Ring {
+        k: usize,
+    },
+}
+
+/// Treatment of a coordinate that left the box.
+#[derive(Debug, Clone, Copy)]
+pub enum Boundary {
+    /// Mirrored back inside, the velocity component is reversed.
+    Reflect,
+    /// Put on the bound, the velocity component is zeroed.
+    Clamp,
+    /// Resampled uniformly in the range.
+    Reinit,
+}
+
+#[derive(Debug)]
+pub struct SwarmInfo {
+    pub iterations: usize,
+    pub evaluations: usize,
+    /// Mean distance of the particles to the swarm centroid after each iteration.
+    pub diversity: Vec<f64>,
+}
+
+/// Particle swarm over a box. Stops when the diversity of the swarm falls below `eps`.
+#[derive(Clone)]
+pub struct ParticleSwarm<const N: usize> {
+    bounds: SVector<RangeInclusive<f64>, N>,
+    particles: usize,
+    velocity: Velocity,
+    topology: Topology,
+    boundary: Boundary,
+    /// Largest velocity component as a fraction of the width of the range.
+    max_velocity: f64,
+    eps: f64,
+    max_iterations: usize,
+}
+
+impl<const N: usize> ParticleSwarm<N> {
+    pub fn new(bounds: [RangeInclusive<f64>; N], particles: usize, eps: f64) -> Self {
+        assert!(particles > 0, "A swarm needs at least one particle");
+
+        Self {
+            bounds: bounds.into(),
+            particles,
+            velocity: Velocity::Constriction { c1: 2.05, c2: 2.05 },
+            topology: Topology::Global,
+            boundary: Boundary::Reflect,
+            max_velocity: 0.5,
+            eps,
+            max_iterations: 1000 * N,
+        }
+    }
+
+    pub fn with_velocity(self, velocity: Velocity) -> Self {
+        if let Velocity::Constriction { c1, c2 } = velocity {
+            assert!(c1 + c2 > 4.0, "Constriction requires c1 + c2 > 4");
+        }
+        Self { velocity, ..self }
+    }
+
+    pub fn with_topology(self, topology: Topology) -> Self {
+        Self { topology, ..self }
+    }
+
+    pub fn with_boundary(self, boundary: Boundary) -> Self {
+        Self { boundary, ..self }
+    }
+
+    pub fn with_max_velocity(self, max_velocity: f64) -> Self {
+        Self {
+            max_velocity,
+            ..self
+        }
+    }
+
+    pub fn with_max_iterations(self, max_iterations: usize) -> Self {
+        Self {
+            max_iterations,
+            ..self
+        }
+    }
+
+    fn neighbourhood_best(&self, values: &[f64], i: usize) -> usize {
+        let n = self.particles;
+        let neighbours: Vec<usize> = match self.topology {
+            Topology::Global => (0..n).collect(),
+            Topology::Ring { k } => {
+                let k = k.min(n / 2);
+                (0..=2 * k).map(|j| (i + n + j - k) % n).collect()
+            }
+        };
+        neighbours
+            .into_iter()
+            .min_by_key(|&j| OrderedFloat(values[j]))
+            .unwrap()
+    }
+
+    fn confine(&self, x: &mut Point<N>, v: &mut Point<N>) {
+        let mut random = rng();
+        for j in 0..N {
+            let range = &self.bounds[j];
+            let (lo, hi) = (*range.start(), *range.end());
+            if (lo..=hi).contains(&x[j]) {
+                continue;
+            }
+
+            match self.boundary {
+                Boundary::Reflect => {
+                    x[j] = if x[j] < lo {
+                        2.0 * lo - x[j]
+                    } else {
+                        2.0 * hi - x[j]
+                    };
+                    x[j] = x[j].clamp(lo, hi);
+                    v[j] = -v[j];
+                }
+                Boundary::Clamp => {
+                    x[j] = x[j].clamp(lo, hi);
+                    v[j] = 0.0;
+                }
+                Boundary::Reinit => x[j] = random.random_range(range.clone()),
+            }
+        }
+    }
+}
+
+impl<const N: usize> Optimizer for ParticleSwarm<N> {
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = SwarmInfo;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let map_to = |x, y: RangeInclusive<f64>| (y.end() - y.start()) * x + y.start();
+        let width = self.bounds.map(|range| range.end() - range.start());
+        let max_velocity = self.max_velocity * width;
+
+        let mut xs: Vec<Point<N>> = (0..self.particles)
+            .map(|_| Point::<N>::new_random().zip_map(&self.bounds, map_to))
+            .collect();
+        let mut vs: Vec<Point<N>> = (0..self.particles)
+            .map(|_| {
+                (Point::<N>::new_random() * 2.0 - Point::from_element(1.0))
+                    .component_mul(&max_velocity)
+                    / 2.0
+            })
+            .collect();
+        let mut personal = xs.clone();
+        let mut values: Vec<f64> = xs.iter().map(|x| f(*x)).collect();
+        let mut evaluations = self.particles;
+
+        let diversity = |xs: &[Point<N>]| {
+            let centroid = xs.iter().sum::<Point<N>>() / xs.len() as f64;
+            xs.iter().map(|x| (x - centroid).norm()).sum::<f64>() / xs.len() as f64
+        };
+        let mut history = vec![];
+
+        while history.len() < self.max_iterations {
+            for i in 0..self.particles {
+                let g = personal[self.neighbourhood_best(&values, i)];
+                let v = self
+                    .velocity
+                    .update(&vs[i], &(personal[i] - xs[i]), &(g - xs[i]));
+                vs[i] = v.zip_map(&max_velocity, |v, max| v.clamp(-max, max));
+                xs[i] += vs[i];
+                self.confine(&mut xs[i], &mut vs[i]);
+
+                let value = f(xs[i]);
+                evaluations += 1;
+                if value < values[i] {
+                    personal[i] = xs[i];
+                    values[i] = value;
+                }
+            }
+
+            history.push(diversity(&xs));
+            if history.last().unwrap() < &self.eps {
+                break;
+            }
+        }
+
+        let best = (0..self.particles)
+            .min_by_key(|&i| OrderedFloat(values[i]))
+            .unwrap();
+        let info = SwarmInfo {
+            iterations: history.len(),
+            evaluations,
+            diversity: history,
+        };
+        (personal[best], values[best], info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::functions::{Booth, Function, Himmelblau, Rastrigin, Rosenbrok, Tang};
+    use crate::particle_swarm::{Boundary, ParticleSwarm, Topology, Velocity};
+    use crate::task::Task;
+    use test_case::test_case;
+
+    #[test_case(Topology::Global, Boundary::Reflect; "global_reflect")]
+    #[test_case(Topology::Global, Boundary::Clamp; "global_clamp")]
+    #[test_case(Topology::Ring { k: 1 }, Boundary::Reinit; "ring_reinit")]
+    #[test_case(Topology::Ring { k: 2 }, Boundary::Reflect; "ring_reflect")]
+    fn test_rastrigin(topology: Topology, boundary: Boundary) {
+        Task::new(
+            ParticleSwarm::new([-5.12..=5.12, -5.12..=5.12], 40, 1e-9)
+                .with_topology(topology)
+                .with_boundary(boundary),
+            Rastrigin,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test_case(Rosenbrok)]
+    #[test_case(Himmelblau)]
+    #[test_case(Booth)]
+    fn test_inertia<F: Function<2>>(f: F) {
+        Task::new(
+            ParticleSwarm::new([-10.0..=10.0, -10.0..=10.0], 30, 1e-9).with_velocity(
+                Velocity::Inertia {
+                    w: 0.7298,
+                    c1: 1.49618,
+                    c2: 1.49618,
+                },
+            ),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test]
+    fn test_diversity() {
+        let (_, _, info) = Task::new(
+            ParticleSwarm::new([0; 4].map(|_| -5.0..=5.0), 40, 1e-9)
+                .with_topology(Topology::Ring { k: 1 }),
+            Tang,
+        )
+        .solve_space();
+
+        assert_eq!(info.diversity.len(), info.iterations);
+        assert_eq!(info.evaluations, 40 * (info.iterations + 1));
+        assert!(info.diversity.last().unwrap() < info.diversity.first().unwrap());
+    }
+
+    #[test]
+    fn test_ring_wider_than_swarm() {
+        Task::new(
+            ParticleSwarm::new([-10.0..=10.0, -10.0..=10.0], 6, 1e-9)
+                .with_topology(Topology::Ring { k: 10 }),
+            Booth,
+        )
+        .solve_space_check()
+        .check();
+    }
+}
@@ -0,0 +1,215 @@
+use crate::derivatives::Derivatives;
+use crate::functions::Point;
+use crate::line_search::Search;
+use crate::method::Optimizer;
+use nalgebra::SMatrix;
+
+/// Update formula for the inverse Hessian approximation.
+#[derive(Debug, Clone, Copy)]
+pub enum Update {
+    Bfgs,
+    Dfp,
+}
+
+#[derive(Debug)]
+pub struct QuasiNewtonInfo<const N: usize> {
+    pub steps: usize,
+    pub evaluations: usize,
+    pub gradient_evaluations: usize,
+    /// Times the approximation was reset to the identity.
+    pub resets: usize,
+    pub inverse_hessian: SMatrix<f64, N, N>,
+}
+
+#[derive(Clone)]
+pub struct QuasiNewton<const N: usize> {
+    start: Point<N>,
+    derivatives: Derivatives<N>,
+    update: Update,
+    search: Search,
+    eps: f64,
+    max_steps: usize,
+}
+
+impl<const N: usize> QuasiNewton<N> {
+    /// Curvature `s^T y` relative to `|s| |y|` below which the update is skipped and the
+    /// approximation is reset.
+    const CURVATURE: f64 = 1e-12;
+
+    pub fn new(
+        start: Point<N>,
+        derivatives: Derivatives<N>,
+        update: Update,
+        search: impl Into<Search>,
+        eps: f64,
+    ) -> Self {
+        Self {
+            start,
+            derivatives,
+            update,
+            search: search.into(),
+            eps,
+            max_steps: 100_000,
+        }
+    }
+
+    pub fn with_max_steps(self, max_steps: usize) -> Self {
+        Self { max_steps, ..self }
+    }
+
+    fn update(&self, h: &SMatrix<f64, N, N>, s: &Point<N>, y: &Point<N>) -> SMatrix<f64, N, N> {
+        let sy = s.dot(y);
+        match self.update {
+            Update::Bfgs => {
+                let rho = 1.0 / sy;
+                let left = SMatrix::identity() - rho * s * y.transpose();
+                let right = SMatrix::identity() - rho * y * s.transpose();
+                left * h * right + rho * s * s.transpose()
+            }
+            Update::Dfp => {
+                let hy = h * y;
+                h - hy * hy.transpose() / y.dot(&hy) + s * s.transpose() / sy
+            }
+        }
+    }
+}
+
+impl<const N: usize> Optimizer for QuasiNewton<N> {
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = QuasiNewtonInfo<N>;
+
+    fn optimize(&self, f: impl FnMut(Self::X) -> Self::F) -> (Self::X, Self::F, Self::Metadata) {
+        let mut objective = self.derivatives.of(f);
+        let mut x = self.start;
+        let (mut fx, mut gradient) = objective.value_gradient(x);
+        let mut h = SMatrix::<f64, N, N>::identity();
+        let mut scaled = false;
+        let mut resets = 0;
+        let mut r = 0;
+
+        while r < self.max_steps && gradient.norm() >= self.eps {
+            let mut direction = -(h * gradient);
+            let mut slope = gradient.dot(&direction);
+            if slope >= 0.0 {
+                h = SMatrix::identity();
+                scaled = false;
+                resets += 1;
+                direction = -gradient;
+                slope = -gradient.norm_squared();
+            }
+
+            let initial = if scaled {
+                1.0
+            } else {
+                1.0 / gradient.norm().max(1.0)
+            };
+            let (alpha, f_next) =
+                self.search
+                    .step(&mut objective, x, direction, (fx, slope), initial);
+            if alpha == 0.0 {
+                break;
+            }
+
+            let s = alpha * direction;
+            let next = objective.gradient(x + s);
+            let y = next - gradient;
+            (x, fx, gradient) = (x + s, f_next, next);
+            r += 1;
+
+            let sy = s.dot(&y);
+            if sy <= Self::CURVATURE * s.norm() * y.norm() {
+                h = SMatrix::identity();
+                scaled = false;
+                resets += 1;
+                continue;
+            }
+
+            if !scaled {
+                h = SMatrix::identity() * sy / y.norm_squared();
+                scaled = true;
+            }
+            h = self.update(&h, &s, &y);
+        }
+
+        let info = QuasiNewtonInfo {
+            steps: r,
+            evaluations: objective.evaluations,
+            gradient_evaluations: objective.gradient_evaluations,
+            resets,
+            inverse_hessian: h,
+        };
+        (x, fx, info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::brent::Brent;
+    use crate::derivatives::{Derivatives, Difference};
+    use crate::dual::hessian;
+    use crate::functions::{Booth, Function, GenericFunction, Himmelblau, Rosenbrok, Sphere};
+    use crate::line_search::LineSearch;
+    use crate::method::{OneDimensionalMethod, Optimizer};
+    use crate::quasi_newton::{QuasiNewton, Update};
+    use crate::task::Task;
+    use approx::assert_relative_eq;
+    use test_case::test_case;
+
+    #[test_case(Update::Bfgs, Rosenbrok)]
+    #[test_case(Update::Bfgs, Booth)]
+    #[test_case(Update::Bfgs, Himmelblau)]
+    #[test_case(Update::Bfgs, Sphere)]
+    #[test_case(Update::Dfp, Booth)]
+    #[test_case(Update::Dfp, Sphere)]
+    fn test_wolfe<F: GenericFunction<2> + 'static>(update: Update, f: F) {
+        Task::new(
+            QuasiNewton::new(
+                [-1.2, 1.0].into(),
+                Derivatives::automatic::<F>(),
+                update,
+                LineSearch::strong_wolfe(1e-4, 0.9),
+                1e-10,
+            ),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test_case(Update::Bfgs, Rosenbrok)]
+    #[test_case(Update::Dfp, Rosenbrok)]
+    #[test_case(Update::Dfp, Himmelblau)]
+    fn test_exact<F: Function<2>>(update: Update, f: F) {
+        let method = OneDimensionalMethod::from(Brent::new(0.0..=1.0, 1e-12));
+
+        Task::new(
+            QuasiNewton::new(
+                [-1.2, 1.0].into(),
+                Derivatives::Numeric(Difference::Richardson),
+                update,
+                method.auto_bracket(0.0, 1e-2, 60),
+                1e-7,
+            ),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test]
+    fn test_inverse_hessian_at_optimum() {
+        let optimizer = QuasiNewton::new(
+            [-1.2, 1.0].into(),
+            Derivatives::automatic::<Rosenbrok>(),
+            Update::Bfgs,
+            LineSearch::strong_wolfe(1e-4, 0.9),
+            1e-12,
+        );
+        let (x, _, info) = optimizer.optimize(Rosenbrok::f);
+        let expected = hessian::<2, Rosenbrok>(x).try_inverse().unwrap();
+
+        assert!(info.evaluations > info.steps && info.gradient_evaluations > info.steps);
+        assert_relative_eq!(info.inverse_hessian, expected, max_relative = 0.2);
+    }
+}
@@ -0,0 +1,201 @@
+use crate::functions::Point;
+use crate::method::{OneDimensionalMethod, Optimizer, Steps};
+use derive_more::Constructor;
+
+/// How a single stage explores the current basis.
+#[derive(Clone)]
+pub enum Exploration {
+    /// Trial steps along every direction, multiplied by `alpha` on success and by `beta` on
+    /// failure, until each direction has succeeded and failed at least once.
+    Discrete { step: f64, alpha: f64, beta: f64 },
+    /// Exact minimization along every direction.
+    Linear(OneDimensionalMethod),
+}
+
+/// Rosenbrock's method of rotating coordinates.
+#[derive(Constructor, Clone)]
+pub struct RotatingCoordinates<const N: usize> {
+    start: Point<N>,
+    exploration: Exploration,
+    eps: f64,
+}
+
+impl<const N: usize> RotatingCoordinates<N> {
+    /// Gram–Schmidt orthogonalization of the accumulated steps `A_i = sum_{j >= i} lambda_j d_j`.
+    fn rotate(basis: &[Point<N>], lambdas: &[f64]) -> Vec<Point<N>> {
+        let mut rotated: Vec<Point<N>> = Vec::with_capacity(N);
+
+        for i in 0..N {
+            let a: Point<N> = (i..N).map(|j| lambdas[j] * basis[j]).sum();
+            let orthogonalize =
+                |v: Point<N>, rotated: &[Point<N>]| rotated.iter().fold(v, |v, d| v - v.dot(d) * d);
+
+            let mut b = orthogonalize(a, &rotated);
+            if b.norm() < 1e-12 * a.norm().max(1.0) {
+                b = orthogonalize(basis[i], &rotated);
+            }
+            rotated.push(b.normalize());
+        }
+
+        rotated
+    }
+
+    fn discrete_stage(
+        f: &mut impl FnMut(Point<N>) -> f64,
+        basis: &[Point<N>],
+        x: &mut Point<N>,
+        fx: &mut f64,
+        steps: &mut [f64],
+        (alpha, beta): (f64, f64),
+        eps: f64,
+    ) -> Vec<f64> {
+        let mut lambdas = vec![0.0; N];
+        let mut succeeded = [false; N];
+        let mut failed = [false; N];
+
+        while !(succeeded.iter().all(|&s| s) && failed.iter().all(|&s| s))
+            && steps.iter().any(|h| h.abs() >= eps)
+        {
+            for i in 0..N {
+                let y = *x + steps[i] * basis[i];
+                let fy = f(y);
+
+                if fy <= *fx {
+                    (*x, *fx) = (y, fy);
+                    lambdas[i] += steps[i];
+                    steps[i] *= alpha;
+                    succeeded[i] = true;
+                } else {
+                    steps[i] *= beta;
+                    failed[i] = true;
+                }
+            }
+        }
+
+        lambdas
+    }
+}
+
+impl<const N: usize> Optimizer for RotatingCoordinates<N> {
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = Steps;
+
+    fn optimize(
+        &self,
+        mut f: impl FnMut(Self::X) -> Self::F,
+    ) -> (Self::X, Self::F, Self::Metadata) {
+        let mut basis: Vec<Point<N>> = (0..N)
+            .map(|i| Point::from_fn(|j, _| if i == j { 1.0 } else { 0.0 }))
+            .collect();
+        let mut x = self.start;
+        let mut fx = f(x);
+        let mut r = 0;
+
+        match &self.exploration {
+            Exploration::Discrete { step, alpha, beta } => {
+                let mut steps = vec![*step; N];
+
+                loop {
+                    let x0 = x;
+                    let lambdas = Self::discrete_stage(
+                        &mut f,
+                        &basis,
+                        &mut x,
+                        &mut fx,
+                        &mut steps,
+                        (*alpha, *beta),
+                        self.eps,
+                    );
+                    r += 1;
+
+                    if (x - x0).norm() < self.eps {
+                        break;
+                    }
+                    basis = Self::rotate(&basis, &lambdas);
+                    steps.fill(*step);
+                }
+            }
+            Exploration::Linear(optimizer) => loop {
+                let x0 = x;
+                let mut lambdas = vec![0.0; N];
+
+                for (lambda, direction) in lambdas.iter_mut().zip(&basis) {
+                    let (l, fl, _) = optimizer.optimize(|l| f(x + l * direction));
+                    if fl < fx {
+                        *lambda = l;
+                        x += l * direction;
+                        fx = fl;
+                    }
+                }
+                r += 1;
+
+                if (x - x0).norm() < self.eps {
+                    break;
+                }
+                basis = Self::rotate(&basis, &lambdas);
+            },
+        }
+
+        (x, fx, Steps(r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::brent::Brent;
+    use crate::fibonacci::GoldenRatio;
+    use crate::functions::{Booth, Function, Himmelblau, Rosenbrok, Sphere};
+    use crate::method::OneDimensionalMethod;
+    use crate::rotating::{Exploration, RotatingCoordinates};
+    use crate::task::Task;
+    use test_case::test_case;
+
+    #[test_case(Booth; "booth")]
+    #[test_case(Sphere; "sphere")]
+    #[test_case(Himmelblau; "himmelblau")]
+    #[test_case(Rosenbrok; "rosenbrok")]
+    fn test_discrete<F: Function<2>>(f: F) {
+        let exploration = Exploration::Discrete {
+            step: 0.1,
+            alpha: 3.0,
+            beta: -0.5,
+        };
+
+        Task::new(
+            RotatingCoordinates::new([-1.2, 1.0].into(), exploration, 1e-10),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test_case(Booth; "booth")]
+    #[test_case(Sphere; "sphere")]
+    #[test_case(Himmelblau; "himmelblau")]
+    #[test_case(Rosenbrok; "rosenbrok")]
+    fn test_linear<F: Function<2>>(f: F) {
+        let method = OneDimensionalMethod::from(Brent::new(-10.0..=10.0, 1e-10));
+        let exploration = Exploration::Linear(method.auto_bracket(0.0, 0.01, 50));
+
+        Task::new(
+            RotatingCoordinates::new([-1.2, 1.0].into(), exploration, 1e-10),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test]
+    fn test_rosenbrok_golden_ratio() {
+        let method = OneDimensionalMethod::from(GoldenRatio::new(-10.0..=10.0, 1e-10));
+        let exploration = Exploration::Linear(method.auto_bracket(0.0, 0.01, 50));
+
+        Task::new(
+            RotatingCoordinates::new([2.0, 1.5].into(), exploration, 1e-10),
+            Rosenbrok,
+        )
+        .solve_space_check()
+        .check();
+    }
+}
@@ -0,0 +1,340 @@
+use crate::derivatives::Derivatives;
+use crate::functions::Point;
+use crate::method::{Optimizer, Steps};
+use nalgebra::{Cholesky, Matrix2, SMatrix, Vector2};
+
+/// Source of the Hessian of the quadratic model.
+#[derive(Debug, Clone, Copy)]
+pub enum Model {
+    Exact,
+    /// Symmetric rank-one update, may become indefinite.
+    Sr1,
+    Bfgs,
+}
+
+/// Approximate solver of `min g^T p + p^T B p / 2` subject to `|p| <= radius`.
+#[derive(Debug, Clone, Copy)]
+pub enum Subproblem {
+    Cauchy,
+    Dogleg,
+    /// Exact minimizer over the span of the gradient and the Newton step.
+    TwoDimensional,
+    /// Truncated conjugate gradients.
+    Steihaug,
+}
+
+type Hessian<const N: usize> = SMatrix<f64, N, N>;
+
+impl Subproblem {
+    fn solve<const N: usize>(&self, g: &Point<N>, b: &Hessian<N>, radius: f64) -> Point<N> {
+        match self {
+            Subproblem::Cauchy => Self::cauchy(g, b, radius),
+            Subproblem::Dogleg => Self::dogleg(g, b, radius),
+            Subproblem::TwoDimensional => Self::two_dimensional(g, b, radius),
+            Subproblem::Steihaug => Self::steihaug(g, b, radius),
+        }
+    }
+
+    fn cauchy<const N: usize>(g: &Point<N>, b: &Hessian<N>, radius: f64) -> Point<N> {
+        let curvature = g.dot(&(b * g));
+        let norm = g.norm();
+        let tau = if curvature <= 0.0 {
+            1.0
+        } else {
+            (norm.powi(3) / (radius * curvature)).min(1.0)
+        };
+        -tau * radius / norm * g
+    }
+
+    /// Positive `tau` with `|p + tau * d| = radius` for `|p| <= radius`.
+    fn to_boundary<const N: usize>(p: &Point<N>, d: &Point<N>, radius: f64) -> f64 {
+        let a = d.norm_squared();
+        let b = 2.0 * p.dot(d);
+        let c = p.norm_squared() - radius * radius;
+        (-b + (b * b - 4.0 * a * c).max(0.0).sqrt()) / (2.0 * a)
+    }
+
+    fn dogleg<const N: usize>(g: &Point<N>, b: &Hessian<N>, radius: f64) -> Point<N> {
+        let Some(cholesky) = Cholesky::new(*b) else {
+            return Self::cauchy(g, b, radius);
+        };
+
+        let newton = cholesky.solve(&-g);
+        if newton.norm() <= radius {
+            return newton;
+        }
+
+        let steepest = -g.norm_squared() / g.dot(&(b * g)) * g;
+        if steepest.norm() >= radius {
+            return -radius / g.norm() * g;
+        }
+
+        let d = newton - steepest;
+        steepest + Self::to_boundary(&steepest, &d, radius) * d
+    }
+
+    fn two_dimensional<const N: usize>(g: &Point<N>, b: &Hessian<N>, radius: f64) -> Point<N> {
+        let Some(cholesky) = Cholesky::new(*b) else {
+            return Self::steihaug(g, b, radius);
+        };
+
+        let newton = cholesky.solve(&-g);
+        if newton.norm() <= radius {
+            return newton;
+        }
+
+        let v1 = g.normalize();
+        let v2 = newton - newton.dot(&v1) * v1;
+        if v2.norm() < 1e-12 * newton.norm() {
+            return Self::cauchy(g, b, radius);
+        }
+        let v2 = v2.normalize();
+
+        let reduced_g = Vector2::new(g.dot(&v1), g.dot(&v2));
+        let reduced_b = Matrix2::new(
+            v1.dot(&(b * v1)),
+            v1.dot(&(b * v2)),
+            v2.dot(&(b * v1)),
+            v2.dot(&(b * v2)),
+        );
+        let step = |lambda: f64| {
+            (reduced_b + Matrix2::identity() * lambda)
+                .try_inverse()
+                .map(|inverse| -(inverse * reduced_g))
+        };
+
+        // |p(lambda)| decreases monotonically, bisect for the boundary
+        let (mut lo, mut hi) = (0.0, reduced_g.norm() / radius + reduced_b.norm());
+        for _ in 0..100 {
+            let mid = (lo + hi) / 2.0;
+            match step(mid) {
+                Some(p) if p.norm() <= radius => hi = mid,
+                _ => lo = mid,
+            }
+        }
+
+        let p = step(hi).unwrap_or_else(|| -radius / reduced_g.norm() * reduced_g);
+        p[0] * v1 + p[1] * v2
+    }
+
+    fn steihaug<const N: usize>(g: &Point<N>, b: &Hessian<N>, radius: f64) -> Point<N> {
+        let tolerance = g.norm().sqrt().min(0.5) * g.norm();
+        let mut z = Point::<N>::zeros();
+        let mut r = *g;
+        let mut d = -g;
+
+        for _ in 0..=2 * N {
+            let bd = b * d;
+            let curvature = d.dot(&bd);
+            if curvature <= 0.0 {
+                return z + Self::to_boundary(&z, &d, radius) * d;
+            }
+
+            let alpha = r.norm_squared() / curvature;
+            let next = z + alpha * d;
+            if next.norm() >= radius {
+                return z + Self::to_boundary(&z, &d, radius) * d;
+            }
+
+            let r_next = r + alpha * bd;
+            if r_next.norm() < tolerance {
+                return next;
+            }
+
+            d = -r_next + r_next.norm_squared() / r.norm_squared() * d;
+            (z, r) = (next, r_next);
+        }
+        z
+    }
+}
+
+#[derive(Clone)]
+pub struct TrustRegion<const N: usize> {
+    start: Point<N>,
+    derivatives: Derivatives<N>,
+    model: Model,
+    subproblem: Subproblem,
+    radius: f64,
+    max_radius: f64,
+    eta: f64,
+    eps: f64,
+    max_steps: usize,
+}
+
+impl<const N: usize> TrustRegion<N> {
+    pub fn new(
+        start: Point<N>,
+        derivatives: Derivatives<N>,
+        model: Model,
+        subproblem: Subproblem,
+        eps: f64,
+    ) -> Self {
+        Self {
+            start,
+            derivatives,
+            model,
+            subproblem,
+            radius: 1.0,
+            max_radius: 100.0,
+            eta: 0.1,
+            eps,
+            max_steps: 100_000,
+        }
+    }
+
+    pub fn with_radius(self, radius: f64, max_radius: f64) -> Self {
+        Self {
+            radius,
+            max_radius,
+            ..self
+        }
+    }
+
+    /// Minimal ratio of actual to predicted reduction for a step to be accepted.
+    pub fn with_eta(self, eta: f64) -> Self {
+        Self { eta, ..self }
+    }
+
+    pub fn with_max_steps(self, max_steps: usize) -> Self {
+        Self { max_steps, ..self }
+    }
+
+    fn update(&self, b: &Hessian<N>, s: &Point<N>, y: &Point<N>) -> Hessian<N> {
+        match self.model {
+            Model::Exact => *b,
+            Model::Sr1 => {
+                let v = y - b * s;
+                let denominator = v.dot(s);
+                if denominator.abs() < 1e-8 * s.norm() * v.norm() {
+                    *b
+                } else {
+                    b + v * v.transpose() / denominator
+                }
+            }
+            Model::Bfgs => {
+                let sy = s.dot(y);
+                if sy <= 1e-12 * s.norm() * y.norm() {
+                    return *b;
+                }
+                let bs = b * s;
+                b - bs * bs.transpose() / s.dot(&bs) + y * y.transpose() / sy
+            }
+        }
+    }
+}
+
+impl<const N: usize> Optimizer for TrustRegion<N> {
+    type X = Point<N>;
+    type F = f64;
+    type Metadata = Steps;
+
+    fn optimize(&self, f: impl FnMut(Self::X) -> Self::F) -> (Self::X, Self::F, Self::Metadata) {
+        let mut objective = self.derivatives.of(f);
+        let mut x = self.start;
+        let (mut fx, mut gradient) = objective.value_gradient(x);
+        let mut b = match self.model {
+            Model::Exact => objective.hessian(x),
+            Model::Sr1 | Model::Bfgs => Hessian::identity(),
+        };
+        let mut radius = self.radius;
+        let mut r = 0;
+
+        while r < self.max_steps && gradient.norm() >= self.eps && radius > f64::EPSILON {
+            let p = self.subproblem.solve(&gradient, &b, radius);
+            let predicted = -(gradient.dot(&p) + 0.5 * p.dot(&(b * p)));
+            let f_next = objective.value(x + p);
+            let rho = (fx - f_next) / predicted;
+            r += 1;
+
+            if rho.is_nan() || rho < 0.25 {
+                radius *= 0.25;
+            } else if rho > 0.75 && (p.norm() - radius).abs() <= 1e-8 * radius {
+                radius = (2.0 * radius).min(self.max_radius);
+            }
+
+            if !matches!(self.model, Model::Exact) || rho > self.eta {
+                let next = objective.gradient(x + p);
+                b = self.update(&b, &p, &(next - gradient));
+
+                if rho > self.eta {
+                    (x, fx, gradient) = (x + p, f_next, next);
+                    if let Model::Exact = self.model {
+                        b = objective.hessian(x);
+                    }
+                }
+            }
+        }
+
+        (x, fx, Steps(r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::derivatives::{Derivatives, Difference};
+    use crate::functions::{Booth, Function, GenericFunction, Himmelblau, Rosenbrok, Sphere};
+    use crate::task::Task;
+    use crate::trust_region::{Model, Subproblem, TrustRegion};
+    use test_case::test_case;
+
+    #[test_case(Subproblem::Cauchy; "cauchy")]
+    #[test_case(Subproblem::Dogleg; "dogleg")]
+    #[test_case(Subproblem::TwoDimensional; "two_dimensional")]
+    #[test_case(Subproblem::Steihaug; "steihaug")]
+    fn test_exact_rosenbrok(subproblem: Subproblem) {
+        Task::new(
+            TrustRegion::new(
+                [-1.2, 1.0].into(),
+                Derivatives::automatic::<Rosenbrok>(),
+                Model::Exact,
+                subproblem,
+                1e-9,
+            ),
+            Rosenbrok,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test_case(Model::Sr1, Subproblem::Steihaug, Rosenbrok)]
+    #[test_case(Model::Sr1, Subproblem::TwoDimensional, Himmelblau)]
+    #[test_case(Model::Bfgs, Subproblem::Dogleg, Rosenbrok)]
+    #[test_case(Model::Bfgs, Subproblem::TwoDimensional, Booth)]
+    #[test_case(Model::Bfgs, Subproblem::Steihaug, Sphere)]
+    fn test_quasi_newton<F: GenericFunction<2> + 'static>(
+        model: Model,
+        subproblem: Subproblem,
+        f: F,
+    ) {
+        Task::new(
+            TrustRegion::new(
+                [-1.2, 1.0].into(),
+                Derivatives::automatic::<F>(),
+                model,
+                subproblem,
+                1e-9,
+            ),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+
+    #[test_case(Himmelblau; "himmelblau")]
+    #[test_case(Booth; "booth")]
+    fn test_finite_differences<F: Function<2>>(f: F) {
+        Task::new(
+            TrustRegion::new(
+                [0.0, 0.0].into(),
+                Derivatives::Numeric(Difference::Central),
+                Model::Exact,
+                Subproblem::Dogleg,
+                1e-7,
+            )
+            .with_radius(0.5, 10.0),
+            f,
+        )
+        .solve_space_check()
+        .check();
+    }
+}
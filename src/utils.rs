@@ -1,4 +1,6 @@
 use num::{FromPrimitive, Num};
+use rand::{Rng, RngExt};
+use std::f64::consts::{PI, TAU};
 
 pub fn linspace<T: Num + PartialOrd + Copy + FromPrimitive>(
     start: T,
@@ -74,3 +76,15 @@ pub trait False {}
 
 impl False for Bool<false> {}
 impl True for Bool<true> {}
+
+/// Sample of the standard normal distribution by the Box–Muller transform.
+pub fn normal(random: &mut impl Rng) -> f64 {
+    let u = 1.0 - random.random::<f64>();
+    let v = random.random::<f64>();
+    (-2.0 * u.ln()).sqrt() * (TAU * v).cos()
+}
+
+/// Sample of the standard Cauchy distribution by inversion.
+pub fn cauchy(random: &mut impl Rng) -> f64 {
+    (PI * (random.random::<f64>() - 0.5)).tan()
+}
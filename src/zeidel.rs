@@ -68,14 +68,30 @@ impl<const N: usize> GaussZeidel<N> {
 #[cfg(test)]
 mod tests {
     use crate::binary::Binary;
+    use crate::brent::Brent;
     use crate::def_test;
-    use crate::fibonacci::GoldenRatio;
+    use crate::fibonacci::{Fibonacci, GoldenRatio};
     use crate::functions::{Booth, Function, Himmelblau, Rosenbrok, Sphere};
     use crate::method::OneDimensionalMethod;
     use crate::task::{Check, Task};
     use crate::zeidel::GaussZeidel;
     use std::sync::LazyLock;
 
+    #[test]
+    fn test_gauss_zeidel_booth_brent() {
+        Task::new(
+            GaussZeidel::new(
+                [-4.0, -4.0].into(),
+                Brent::new(-10.0..=10.0, 1e-9).into(),
+                1e-12,
+                1e-12,
+            ),
+            Booth,
+        )
+        .solve_space_check()
+        .check();
+    }
+
     #[test]
     fn test_gauss_zeidel_rosenbrok() {
         Task::new(
@@ -92,10 +108,11 @@ mod tests {
         .check();
     }
 
-    static METHODS: LazyLock<[OneDimensionalMethod; 2]> = LazyLock::new(|| {
+    static METHODS: LazyLock<[OneDimensionalMethod; 3]> = LazyLock::new(|| {
         [
             GoldenRatio::new(-10.0..=10.0, 1e-6).into(),
             Binary::new(-10.0..=10.0, 1e-6, 1e-7).into(),
+            Fibonacci::new(-10.0..=10.0, 1e-6).into(),
         ]
     });
 
@@ -116,6 +133,10 @@ mod tests {
         helper(Booth, &METHODS[0]).check()
     });
 
+    def_test!(test_gauss_zeidel_booth_fibonacci {
+        helper(Booth, &METHODS[2]).check()
+    });
+
     def_test!(test_gauss_zeidel_himmelblau_golden_ratio {
         helper(Himmelblau, &METHODS[0]).check()
     });